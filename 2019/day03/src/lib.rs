@@ -0,0 +1,296 @@
+#![crate_name = "aoc_2019_day03"]
+
+//! ## --- Day 3: Crossed Wires ---
+//!
+//! The gravity assist was successful, and you're well on your way to the Venus refuelling station. During the rush back on Earth, the fuel management system wasn't completely installed, so that's next on the priority list.
+//!
+//! Opening the front panel reveals a jumble of wires. Specifically, two wires are connected to a central port and extend outward on a grid. You trace the path each wire takes as it leaves the central port, one wire per line of text (your puzzle input).
+//!
+//! The wires twist and turn, but the two wires occasionally cross paths. To fix the circuit, you need to find the intersection point closest to the central port. Because the wires are on a grid, use the Manhattan distance for this measurement. While the wires do technically cross right at the central port where they both start, this point does not count, nor does a wire count as crossing with itself.
+//!
+//! For example, if the first wire's path is R8,U5,L5,D3, then starting from the central port (o), it goes right 8, up 5, left 5, and finally down 3:
+//!
+//! ```
+//! ...........
+//! ...........
+//! ...........
+//! ....+----+.
+//! ....|....|.
+//! ....|....|.
+//! ....|....|.
+//! .........|.
+//! .o-------+.
+//! ...........
+//! ```
+//!
+//! Then, if the second wire's path is U7,R6,D4,L4, it goes up 7, right 6, down 4, and left 4:
+//!
+//! ```
+//! ...........
+//! .+-----+...
+//! .|.....|...
+//! .|..+--X-+.
+//! .|..|..|.|.
+//! .|.-X--+.|.
+//! .|..|....|.
+//! .|.......|.
+//! .o-------+.
+//! ...........
+//! ```
+//!
+//! These wires cross at two locations (marked X), but the lower-left one is closer to the central port: its distance is 3 + 3 = 6.
+//!
+//! Here are a few more examples:
+//!
+//! ```
+//! R75,D30,R83,U83,L12,D49,R71,U7,L72
+//! U62,R66,U55,R34,D71,R55,D58,R83 = distance 159
+//! R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
+//! U98,R91,D20,R16,D67,R40,U7,R15,U6,R7 = distance 135
+//! ```
+//!
+//! What is the Manhattan distance from the central port to the closest intersection?
+//!
+//! Your puzzle answer was 217.
+//!
+//! ## --- Part Two ---
+//!
+//! It turns out that this circuit is very timing-sensitive; you actually need to minimize the signal delay.
+//!
+//! To do this, calculate the number of steps each wire takes to reach each intersection; choose the intersection where the sum of both wires' steps is lowest. If a wire visits a position on the grid multiple times, use the steps value from the first time it visits that position when calculating the total value of a specific intersection.
+//!
+//! The number of steps a wire takes is the total number of grid squares the wire has entered to get to that location, including the intersection being considered. Again consider the example from above:
+//!
+//! ```
+//! ...........
+//! .+-----+...
+//! .|.....|...
+//! .|..+--X-+.
+//! .|..|..|.|.
+//! .|.-X--+.|.
+//! .|..|....|.
+//! .|.......|.
+//! .o-------+.
+//! ...........
+//! ```
+//!
+//! In the above example, the intersection closest to the central port is reached after 8+5+5+2 = 20 steps by the first wire and 7+6+4+3 = 20 steps by the second wire for a total of 20+20 = 40 steps.
+//!
+//! However, the top-right intersection is better: the first wire takes only 8+5+2 = 15 and the second wire takes only 7+6+2 = 15, a total of 15+15 = 30 steps.
+//!
+//! Here are the best steps for the extra examples from above:
+//!
+//! ```
+//! R75,D30,R83,U83,L12,D49,R71,U7,L72
+//! U62,R66,U55,R34,D71,R55,D58,R83 = 610 steps
+//! R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
+//! U98,R91,D20,R16,D67,R40,U7,R15,U6,R7 = 410 steps
+//! ```
+//!
+//! What is the fewest combined steps the wires must take to reach an intersection?
+//!
+//! Your puzzle answer was 3454.
+
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Direction, Example, ParseError, Point2, Solution};
+
+/// the path of a wire, the direction and length of each of its segments
+pub type Wire = Vec<(Direction, i64)>;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = (Wire, Wire);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::text("R8,U5,L5,D3\nU7,R6,D4,L4\n", 1, "6"),
+        Example::text(
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83\n",
+            1,
+            "159",
+        ),
+        Example::text(
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7\n",
+            1,
+            "135",
+        ),
+        Example::text("R8,U5,L5,D3\nU7,R6,D4,L4\n", 2, "30"),
+        Example::text(
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83\n",
+            2,
+            "610",
+        ),
+        Example::text(
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7\n",
+            2,
+            "410",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).ok_or("the wires don't cross").into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).ok_or("the wires don't cross").into()
+    }
+}
+
+/// the distance from the start to the closest crossing, `None` if the wires
+/// don't cross
+pub fn part1(wires: &(Wire, Wire)) -> Option<i64> {
+    let line1 = trace(&wires.0);
+    let line2 = trace(&wires.1);
+
+    // a line crossing itself isn't an intersection
+    line1
+        .keys()
+        .filter(|&point| line2.contains_key(point))
+        .map(|point| point.manhattan(Point2::ORIGIN))
+        .min()
+}
+
+/// the fewest combined steps to a crossing, `None` if the wires don't cross
+pub fn part2(wires: &(Wire, Wire)) -> Option<usize> {
+    let line1 = trace(&wires.0);
+    let line2 = trace(&wires.1);
+
+    // sum up the number of steps for each intersection and return the minimum
+    line1
+        .iter()
+        .filter_map(|(point, steps)| Some(steps + line2.get(point)?))
+        .min()
+}
+
+pub fn parse_input(string: &str) -> Result<(Wire, Wire), ParseError> {
+    let mut lines = string.lines().map(|line| {
+        line.split(',')
+            .map(|segment| Direction::parse_move(string, segment))
+            .collect::<Result<Wire, ParseError>>()
+    });
+
+    match (lines.next(), lines.next(), lines.next()) {
+        (Some(line1), Some(line2), None) => Ok((line1?, line2?)),
+        _ => Err(ParseError::new("expected 2 lines, check your input")),
+    }
+}
+
+/// returns every point the wire goes through (not counting the start) and
+/// the fewest steps along the wire it takes to get there
+fn trace(wire: &[(Direction, i64)]) -> HashMap<Point2, usize> {
+    let mut points = HashMap::new();
+    let mut current = Point2::ORIGIN;
+    let mut steps = 0;
+
+    for &(direction, length) in wire {
+        for _ in 0..length {
+            current += direction.offset();
+            steps += 1;
+            points.entry(current).or_insert(steps);
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let directions: Vec<(Vec<String>, Vec<String>)> = vec![
+            (
+                vec![
+                    "R75".into(),
+                    "D30".into(),
+                    "R83".into(),
+                    "U83".into(),
+                    "L12".into(),
+                    "D49".into(),
+                    "R71".into(),
+                    "U7".into(),
+                    "L72".into(),
+                ],
+                vec![
+                    "U62".into(),
+                    "R66".into(),
+                    "U55".into(),
+                    "R34".into(),
+                    "D71".into(),
+                    "R55".into(),
+                    "D58".into(),
+                    "R83".into(),
+                ],
+            ),
+            (
+                vec![
+                    "R98".into(),
+                    "U47".into(),
+                    "R26".into(),
+                    "D63".into(),
+                    "R33".into(),
+                    "U87".into(),
+                    "L62".into(),
+                    "D20".into(),
+                    "R33".into(),
+                    "U53".into(),
+                    "R51".into(),
+                ],
+                vec![
+                    "U98".into(),
+                    "R91".into(),
+                    "D20".into(),
+                    "R16".into(),
+                    "D67".into(),
+                    "R40".into(),
+                    "U7".into(),
+                    "R15".into(),
+                    "U6".into(),
+                    "R7".into(),
+                ],
+            ),
+        ];
+
+        let expected = [159, 135];
+
+        for (i, (line1, line2)) in directions.iter().enumerate() {
+            let input = parse_input(&format!("{}\n{}", line1.join(","), line2.join(","))).unwrap();
+            let result = part1(&input);
+            assert_eq!(result, Some(expected[i]));
+        }
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        #[allow(clippy::type_complexity)]
+        let points: Vec<((i64, i64), (i64, i64), i64)> = vec![
+            ((0, 0), (0, 0), 0),
+            ((0, 0), (1, 0), 1),
+            ((0, 0), (0, 1), 1),
+            ((0, 0), (-1, 0), 1),
+            ((0, 0), (0, -1), 1),
+            ((0, 0), (1, 1), 2),
+            ((0, 0), (-1, -1), 2),
+            ((0, 0), (-1, 1), 2),
+            ((0, 0), (1, -1), 2),
+            ((0, 0), (2, 0), 2),
+            ((0, 0), (0, 2), 2),
+            ((0, 0), (-2, 0), 2),
+            ((0, 0), (0, -2), 2),
+            ((0, 0), (2, 2), 4),
+            ((0, 0), (-2, -2), 4),
+            ((0, 0), (2, -2), 4),
+            ((0, 0), (-2, 2), 4),
+        ];
+
+        for (p1, p2, expected) in points {
+            assert_eq!(Point2::from(p1).manhattan(p2.into()), expected);
+        }
+    }
+}
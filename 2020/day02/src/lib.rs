@@ -0,0 +1,198 @@
+#![crate_name = "aoc_2020_day02"]
+
+//! ## --- Day 2: Password Philosophy ---
+//!
+//! Your flight departs in a few days from the coastal airport; the easiest way down to the coast from here is via toboggan.
+//!
+//! The shopkeeper at the North Pole Toboggan Rental Shop is having a bad day. "Something's wrong with our computers; we can't log in!" You ask if you can take a look.
+//!
+//! Their password database seems to be a little corrupted: some of the passwords wouldn't have been allowed by the Official Toboggan Corporate Policy that was in effect when they were chosen.
+//!
+//! To try to debug the problem, they have created a list (your puzzle input) of passwords (according to the corrupted database) and the corporate policy when that password was set.
+//!
+//! For example, suppose you have the following list:
+//!
+//! ```
+//! 1-3 a: abcde
+//! 1-3 b: cdefg
+//! 2-9 c: ccccccccc
+//! ```
+//!
+//! Each line gives the password policy and then the password. The password policy indicates the lowest and highest number of times a given letter must appear for the password to be valid. For example, 1-3 a means that the password must contain a at least 1 time and at most 3 times.
+//!
+//! In the above example, 2 passwords are valid. The middle password, cdefg, is not; it contains no instances of b, but needs at least 1. The first and third passwords are valid: they contain one a or nine c, both within the limits of their respective policies.
+//!
+//! How many passwords are valid according to their policies?
+//!
+//! Your puzzle answer was 398.
+//!
+//! ## --- Part Two ---
+//!
+//! While it appears you validated the passwords correctly, they don't seem to be what the Official Toboggan Corporate Authentication System is expecting.
+//!
+//! The shopkeeper suddenly realizes that he just accidentally explained the password policy rules from his old job at the sled rental place down the street! The Official Toboggan Corporate Policy actually works a little differently.
+//!
+//! Each policy actually describes two positions in the password, where 1 means the first character, 2 means the second character, and so on. (Be careful; Toboggan Corporate Policies have no concept of "index zero"!) Exactly one of these positions must contain the given letter. Other occurrences of the letter are irrelevant for the purposes of policy enforcement.
+//!
+//! Given the same example list from above:
+//!
+//! ```
+//! 1-3 a: abcde is valid: position 1 contains a and position 3 does not.
+//! 1-3 b: cdefg is invalid: neither position 1 nor position 3 contains b.
+//! 2-9 c: ccccccccc is invalid: both position 2 and position 9 contain c.
+//! ```
+//!
+//! How many passwords are valid according to the new interpretation of the policies?
+//!
+//! Your puzzle answer was 562.
+
+use aoc_common::log::trace;
+use aoc_common::{parse, Answer, Example, ParseError, Solution};
+
+/// a row of the password database: a policy for a letter and the password
+/// it applies to. `min` and `max` are the allowed number of times the letter
+/// appears for part 1, and the (1-based) positions to check for part 2
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    pub min: usize,
+    pub max: usize,
+    pub letter: char,
+    pub password: String,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Policy>;
+
+    const EXAMPLES: &'static [Example] = &[Example::doc(0, 1, "2"), Example::doc(0, 2, "1")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// parses the password database, one "policy: password" row per line
+pub fn parse_input(contents: &str) -> Result<Vec<Policy>, ParseError> {
+    contents
+        .lines()
+        .map(|line| parse_row(contents, line))
+        .collect()
+}
+
+/// parses a row of the input in the format:
+///     min-max letter: password
+fn parse_row(contents: &str, row: &str) -> Result<Policy, ParseError> {
+    let (policy, password) = row
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at(contents, row, "expected 'policy: password'"))?;
+    let (nums, letter) = policy
+        .split_once(' ')
+        .ok_or_else(|| ParseError::at(contents, policy, "expected 'min-max letter'"))?;
+    let (min, max) = nums
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(contents, nums, "expected 'min-max'"))?;
+
+    // the numbers are positions in part 2, which start at 1
+    let (min, max): (usize, usize) = (parse::number(contents, min)?, parse::number(contents, max)?);
+    if min == 0 {
+        return Err(ParseError::at(contents, nums, "positions start at 1"));
+    }
+    if min > max {
+        return Err(ParseError::at(contents, nums, "expected min <= max"));
+    }
+
+    let mut letters = letter.chars();
+    let letter = match (letters.next(), letters.next()) {
+        (Some(letter), None) => letter,
+        _ => return Err(ParseError::at(contents, letter, "expected a single letter")),
+    };
+
+    Ok(Policy {
+        min,
+        max,
+        letter,
+        password: password.to_string(),
+    })
+}
+
+/// counts the number of valid passwords (based on part1 criteria)
+pub fn part1(input: &[Policy]) -> usize {
+    input.iter().filter(|row| password_is_valid_p1(row)).count()
+}
+
+/// counts the number of valid passwords (based on part2 criteria)
+pub fn part2(input: &[Policy]) -> usize {
+    input.iter().filter(|row| password_is_valid_p2(row)).count()
+}
+
+/// determines if the password is valid (based on part1 criteria): the letter
+/// appears between min and max times
+fn password_is_valid_p1(policy: &Policy) -> bool {
+    let count = policy
+        .password
+        .chars()
+        .filter(|&ch| ch == policy.letter)
+        .count();
+
+    (policy.min..=policy.max).contains(&count)
+}
+
+/// determines if the password is valid (based on part2 criteria): the letter
+/// is at exactly one of the two positions
+fn password_is_valid_p2(policy: &Policy) -> bool {
+    trace!("{:?}", policy);
+
+    let at = |pos: usize| policy.password.chars().nth(pos - 1) == Some(policy.letter);
+
+    at(policy.min) != at(policy.max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+
+    #[test]
+    fn test_parse_input() {
+        let input = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(
+            input[0],
+            Policy {
+                min: 1,
+                max: 3,
+                letter: 'a',
+                password: "abcde".to_string()
+            }
+        );
+        assert_eq!(part1(&input), 2);
+        assert_eq!(part2(&input), 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_input("5-2 a: abc").is_err());
+        assert!(parse_input("0-2 a: abc").is_err());
+        assert!(parse_input("1-2 ab: abc").is_err());
+        assert!(parse_input("1-2 a abc").is_err());
+        assert!(parse_input("1-x a: abc").is_err());
+    }
+
+    #[test]
+    fn test_positions_past_the_end() {
+        let input = parse_input("2-9 c: cc").unwrap();
+
+        assert_eq!(part1(&input), 1);
+        assert_eq!(part2(&input), 1);
+    }
+}
@@ -0,0 +1,143 @@
+#![crate_name = "aoc_2020_day03"]
+
+//! ## --- Day 3: Toboggan Trajectory ---
+//!
+//! With the toboggan login problems resolved, you set off toward the airport. While travel by toboggan might be easy, it's certainly not safe: there's very minimal steering and the area is covered in trees. You'll need to see which angles will take you near the fewest trees.
+//!
+//! Due to the local geology, trees in this area only grow on exact integer coordinates in a grid. You make a map (your puzzle input) of the open squares (.) and trees (#) you can see. For example:
+//!
+//! ```
+//! ..##.......
+//! #...#...#..
+//! .#....#..#.
+//! ..#.#...#.#
+//! .#...##..#.
+//! ..#.##.....
+//! .#.#.#....#
+//! .#........#
+//! #.##...#...
+//! #...##....#
+//! .#..#...#.#
+//! ```
+//!
+//! These aren't the only trees, though; due to something you read about once involving arboreal genetics and biome stability, the same pattern repeats to the right many times:
+//!
+//! ```
+//! ..##.........##.........##.........##.........##.........##.......  --->
+//! #...#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
+//! .#....#..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
+//! ..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
+//! .#...##..#..#...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
+//! ..#.##.......#.##.......#.##.......#.##.......#.##.......#.##.....  --->
+//! .#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
+//! .#........#.#........#.#........#.#........#.#........#.#........#
+//! #.##...#...#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...
+//! #...##....##...##....##...##....##...##....##...##....##...##....#
+//! .#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
+//! ```
+//!
+//! You start on the open square (.) in the top-left corner and need to reach the bottom (below the bottom-most row on your map).
+//!
+//! The toboggan can only follow a few specific slopes (you opted for a cheaper model that prefers rational numbers); start by counting all the trees you would encounter for the slope right 3, down 1:
+//!
+//! From your starting position at the top-left, check the position that is right 3 and down 1. Then, check the position that is right 3 and down 1 from there, and so on until you go past the bottom of the map.
+//!
+//! The locations you'd check in the above example are marked here with O where there was an open square and X where there was a tree:
+//!
+//! ```
+//! ..##.........##.........##.........##.........##.........##.......  --->
+//! #..O#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
+//! .#....X..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
+//! ..#.#...#O#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
+//! .#...##..#..X...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
+//! ..#.##.......#.X#.......#.##.......#.##.......#.##.......#.##.....  --->
+//! .#.#.#....#.#.#.#.O..#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
+//! .#........#.#........X.#........#.#........#.#........#.#........#
+//! #.##...#...#.##...#...#.X#...#...#.##...#...#.##...#...#.##...#...
+//! #...##....##...##....##...#X....##...##....##...##....##...##....#
+//! .#..#...#.#.#..#...#.#.#..#...X.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
+//! ```
+//!
+//! In this example, traversing the map using this slope would cause you to encounter 7 trees.
+//!
+//! Starting at the top-left corner of your map and following a slope of right 3 and down 1, how many trees would you encounter?
+//!
+//! Your puzzle answer was 159.
+//!
+//! ## --- Part Two ---
+//!
+//! Time to check the rest of the slopes - you need to minimize the probability of a sudden arboreal stop, after all.
+//!
+//! Determine the number of trees you would encounter if, for each of the following slopes, you start at the top-left corner and traverse the map all the way to the bottom:
+//!
+//! ```
+//! Right 1, down 1.
+//! Right 3, down 1. (This is the slope you already checked.)
+//! Right 5, down 1.
+//! Right 7, down 1.
+//! Right 1, down 2.
+//! ```
+//!
+//! In the above example, these slopes would find 2, 7, 3, 4, and 2 tree(s) respectively; multiplied together, these produce the answer 336.
+//!
+//! What do you get if you multiply together the number of trees encountered on each of the listed slopes?
+//!
+//! Your puzzle answer was 6419669520.
+
+use aoc_common::{Answer, Example, Grid, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[Example::doc(0, 1, "7"), Example::doc(0, 2, "336")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        count_trees_p1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        count_trees_p2(input).into()
+    }
+}
+
+/// parses the forest into a grid of chars. the forest repeats to the right
+/// forever, so the grid wraps around horizontally
+pub fn parse_input(contents: &str) -> Result<Grid<char>, ParseError> {
+    Ok(Grid::parse(contents, ".#")?.wrap_horizontally())
+}
+
+/// performs the tree counting for part1
+pub fn count_trees_p1(forest: &Grid<char>) -> i32 {
+    count_trees(forest, 3, 1)
+}
+
+/// performs the tree counting for part2
+pub fn count_trees_p2(forest: &Grid<char>) -> i64 {
+    let res = [
+        count_trees(forest, 1, 1).into(),
+        count_trees(forest, 3, 1).into(),
+        count_trees(forest, 5, 1).into(),
+        count_trees(forest, 7, 1).into(),
+        count_trees(forest, 1, 2).into(),
+    ];
+
+    res.iter().product()
+}
+
+/// counts the number of trees given a forest, a right_slope, and a down_slope
+/// starting from the top left corner, where the trees are represented by a
+/// '#' and the empty space is represented by a '.'
+fn count_trees(forest: &Grid<char>, right_slope: isize, down_slope: isize) -> i32 {
+    let start = (0, 0);
+
+    std::iter::once(start)
+        .chain(forest.ray(start, (down_slope, right_slope)))
+        .filter(|&pos| forest[pos] == '#')
+        .count() as i32
+}
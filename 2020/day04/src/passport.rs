@@ -38,6 +38,7 @@ pub trait ValidatePassport {
     fn validate_pid(pid: String) -> bool;
 
     /// validates cid, automatically true if it exists
+    #[allow(dead_code)]
     fn validate_cid(cid: String) -> bool;
 }
 
@@ -56,7 +57,7 @@ impl ValidatePassport for Passport {
         true
     }
     fn is_valid_p2(&self) -> bool {
-        let required_fields = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+        let required_fields = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
         let validation_fns: Vec<fn(String) -> bool> = vec![
             Self::validate_byr,
             Self::validate_iyr,
@@ -0,0 +1,231 @@
+#![crate_name = "aoc_2020_day07"]
+
+//! ## --- Day 7: Handy Haversacks ---
+//!
+//! You land at the regional airport in time for your next flight. In fact, it looks like you'll even have time to grab some food: all flights are currently delayed due to issues in luggage processing.
+//!
+//! Due to recent aviation regulations, many rules (your puzzle input) are being enforced about bags and their contents; bags must be color-coded and must contain specific quantities of other color-coded bags. Apparently, nobody responsible for these regulations considered how long they would take to enforce!
+//!
+//! For example, consider the following rules:
+//!
+//! ```
+//! light red bags contain 1 bright white bag, 2 muted yellow bags.
+//! dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+//! bright white bags contain 1 shiny gold bag.
+//! muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+//! shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+//! dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+//! vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+//! faded blue bags contain no other bags.
+//! dotted black bags contain no other bags.
+//! ```
+//!
+//! These rules specify the required contents for 9 bag types. In this example, every faded blue bag is empty, every vibrant plum bag contains 11 bags (5 faded blue and 6 dotted black), and so on.
+//!
+//! You have a shiny gold bag. If you wanted to carry it in at least one other bag, how many different bag colors would be valid for the outermost bag? (In other words: how many colors can, eventually, contain at least one shiny gold bag?)
+//!
+//! In the above rules, the following options would be available to you:
+//!
+//! ```
+//! A bright white bag, which can hold your shiny gold bag directly.
+//! A muted yellow bag, which can hold your shiny gold bag directly, plus some other bags.
+//! A dark orange bag, which can hold bright white and muted yellow bags, either of which could then hold your shiny gold bag.
+//! A light red bag, which can hold bright white and muted yellow bags, either of which could then hold your shiny gold bag.
+//! So, in this example, the number of bag colors that can eventually contain at least one shiny gold bag is 4.
+//! ```
+//!
+//! How many bag colors can eventually contain at least one shiny gold bag? (The list of rules is quite long; make sure you get all of it.)
+//!
+//! Your puzzle answer was 235.
+//!
+//! ## --- Part Two ---
+//!
+//! It's getting pretty expensive to fly these days - not because of ticket prices, but because of the ridiculous number of bags you need to buy!
+//!
+//! Consider again your shiny gold bag and the rules from the above example:
+//!
+//! ```
+//! faded blue bags contain 0 other bags.
+//! dotted black bags contain 0 other bags.
+//! vibrant plum bags contain 11 other bags: 5 faded blue bags and 6 dotted black bags.
+//! dark olive bags contain 7 other bags: 3 faded blue bags and 4 dotted black bags.
+//! ```
+//!
+//! So, a single shiny gold bag must contain 1 dark olive bag (and the 7 bags within it) plus 2 vibrant plum bags (and the 11 bags within each of those): 1 + 1*7 + 2 + 2*11 = 32 bags!
+//!
+//! Of course, the actual rules have a small chance of going several levels deeper than this example; be sure to count all of the bags, even if the nesting becomes topologically impractical!
+//!
+//! Here's another example:
+//!
+//! ```
+//! shiny gold bags contain 2 dark red bags.
+//! dark red bags contain 2 dark orange bags.
+//! dark orange bags contain 2 dark yellow bags.
+//! dark yellow bags contain 2 dark green bags.
+//! dark green bags contain 2 dark blue bags.
+//! dark blue bags contain 2 dark violet bags.
+//! dark violet bags contain no other bags.
+//! ```
+//!
+//! In this example, a single shiny gold bag must contain 126 other bags.
+//!
+//! How many individual bags are required inside your single shiny gold bag?
+//!
+//! Your puzzle answer was 158493.
+
+use std::collections::HashMap;
+
+use aoc_common::{parse, Answer, Example, Graph, ParseError, Solution};
+
+/// a bag and the bags (with their quantities) that it must contain
+pub type Rule = (String, Vec<(i32, String)>);
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Rule>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::doc(0, 1, "4"),
+        Example::doc(0, 2, "32"),
+        Example::doc(3, 2, "126"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input)
+            .expect("the rules were checked for a loop when parsing")
+            .into()
+    }
+}
+
+/// calculates the number of bag colors that can eventually contain at least
+/// one "shiny gold" bag
+pub fn part1(input: &[Rule]) -> i32 {
+    // follow the edges backwards, from a bag to the bags it can go in
+    bag_graph(input).reversed().count_reachable(&"shiny gold") as i32
+}
+
+/// calculates the number of bags inside of a "shiny gold" bag, or `None` if
+/// a shiny gold bag ends up inside itself
+pub fn part2(input: &[Rule]) -> Option<i32> {
+    let graph = bag_graph(input);
+    let order = gold_order(&graph)?;
+
+    // every bag comes before the bags inside it, so going backwards the
+    // count of the bags inside is always known by the time it's needed
+    let mut counts: HashMap<&str, i32> = HashMap::new();
+    for bag in order.into_iter().rev() {
+        let count = graph
+            .edges(&bag)
+            .map(|(inside_bag, qty)| qty + qty * counts[inside_bag])
+            .sum();
+        counts.insert(bag, count);
+    }
+
+    Some(counts.get("shiny gold").copied().unwrap_or_default())
+}
+
+/// sorts the shiny gold bag and the bags inside it so that every bag comes
+/// before the bags inside it, or `None` if they loop. the bags outside a
+/// shiny gold bag are left out, a loop there doesn't matter and they can hold
+/// more bags than fit in an i32
+fn gold_order<'a>(graph: &Graph<&'a str, i32>) -> Option<Vec<&'a str>> {
+    let inside_gold = graph.reachable(&"shiny gold");
+    graph.subgraph(|bag| inside_gold.contains(bag)).toposort()
+}
+
+/// builds a graph with an edge from each bag to the bags inside of it,
+/// weighted by their quantity
+fn bag_graph(input: &[Rule]) -> Graph<&str, i32> {
+    let mut graph = Graph::new();
+
+    for (bag, inside_bags) in input {
+        graph.add_node(bag.as_str());
+
+        // "no other bags" has a quantity of 0 and isn't a bag
+        for (qty, inside_bag) in inside_bags.iter().filter(|(qty, _)| *qty > 0) {
+            graph.add_edge(bag.as_str(), inside_bag.as_str(), *qty);
+        }
+    }
+
+    graph
+}
+
+/// parses the input into one rule per line. the rules can't put a shiny gold
+/// bag inside itself, or it would hold an endless number of bags
+pub fn parse_input(contents: &str) -> Result<Vec<Rule>, ParseError> {
+    let rules = contents
+        .lines()
+        .map(|line| parse_rule(contents, line))
+        .collect::<Result<Vec<Rule>, ParseError>>()?;
+
+    if gold_order(&bag_graph(&rules)).is_none() {
+        return Err(ParseError::new("a shiny gold bag ends up inside itself"));
+    }
+
+    Ok(rules)
+}
+
+/// parses a line into a rule. returns the bag and a vector that contains
+/// the inside bags and their quantities. a bag that contains "no other bags"
+/// has a single inside bag with a quantity of 0
+fn parse_rule(contents: &str, line: &str) -> Result<Rule, ParseError> {
+    // let the key be the first part of the rule
+    let (bag, inside) = line
+        .split_once(" bags contain ")
+        .ok_or_else(|| ParseError::at(contents, line, "expected '<bag> bags contain <bags>'"))?;
+
+    // parse all of the bags _inside_ of the key
+    let inside_bags = inside
+        .split(',')
+        .map(|bag| {
+            let bag = bag.trim_start();
+            let mut words = bag.split_whitespace();
+
+            let qty = match parse::next(contents, bag, words.next(), "quantity")? {
+                "no" => 0,
+                qty => parse::number(contents, qty)?,
+            };
+
+            let color = words.take(2).collect::<Vec<&str>>().join(" ");
+
+            Ok((qty, color))
+        })
+        .collect::<Result<Vec<(i32, String)>, ParseError>>()?;
+
+    Ok((bag.to_string(), inside_bags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loops() {
+        // a loop outside the shiny gold bag doesn't stop it being counted
+        let rules = parse_input(
+            "shiny gold bags contain 2 dark red bags.
+dark red bags contain no other bags.
+light blue bags contain 1 dark green bag.
+dark green bags contain 1 light blue bag, 1 shiny gold bag.",
+        )
+        .unwrap();
+        assert_eq!(part1(&rules), 2);
+        assert_eq!(part2(&rules), Some(2));
+
+        // but one inside it is an error
+        assert!(parse_input(
+            "shiny gold bags contain 2 dark red bags.
+dark red bags contain 1 shiny gold bag."
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,353 @@
+#![crate_name = "aoc_2020_day11"]
+
+//! ## --- Day 11: Seating System ---
+//!
+//! Your plane lands with plenty of time to spare. The final leg of your journey is a ferry that goes directly to the tropical island where you can finally start your vacation. As you reach the waiting area to board the ferry, you realize you're so early, nobody else has even arrived yet!
+//!
+//! By modeling the process people use to choose (or abandon) their seat in the waiting area, you're pretty sure you can predict the best place to sit. You make a quick map of the seat layout (your puzzle input).
+//!
+//! The seat layout fits neatly on a grid. Each position is either floor (.), an empty seat (L), or an occupied seat (#). For example, the initial seat layout might look like this:
+//!
+//! ```
+//! L.LL.LL.LL
+//! LLLLLLL.LL
+//! L.L.L..L..
+//! LLLL.LL.LL
+//! L.LL.LL.LL
+//! L.LLLLL.LL
+//! ..L.L.....
+//! LLLLLLLLLL
+//! L.LLLLLL.L
+//! L.LLLLL.LL
+//! ```
+//!
+//! Now, you just need to model the people who will be arriving shortly. Fortunately, people are entirely predictable and always follow a simple set of rules. All decisions are based on the number of occupied seats adjacent to a given seat (one of the eight positions immediately up, down, left, right, or diagonal from the seat). The following rules are applied to every seat simultaneously:
+//!
+//! ```
+//! If a seat is empty (L) and there are no occupied seats adjacent to it, the seat becomes occupied.
+//! If a seat is occupied (#) and four or more seats adjacent to it are also occupied, the seat becomes empty.
+//! Otherwise, the seat's state does not change.
+//! Floor (.) never changes; seats don't move, and nobody sits on the floor.
+//! ```
+//!
+//! After one round of these rules, every seat in the example layout becomes occupied:
+//!
+//! ```
+//! #.##.##.##
+//! #######.##
+//! #.#.#..#..
+//! ####.##.##
+//! #.##.##.##
+//! #.#####.##
+//! ..#.#.....
+//! ##########
+//! #.######.#
+//! #.#####.##
+//! ```
+//!
+//! After a second round, the seats with four or more occupied adjacent seats become empty again:
+//!
+//! ```
+//! #.LL.L#.##
+//! #LLLLLL.L#
+//! L.L.L..L..
+//! #LLL.LL.L#
+//! #.LL.LL.LL
+//! #.LLLL#.##
+//! ..L.L.....
+//! #LLLLLLLL#
+//! #.LLLLLL.L
+//! #.#LLLL.##
+//! ```
+//!
+//! This process continues for three more rounds:
+//!
+//! ```
+//! #.##.L#.##
+//! #L###LL.L#
+//! L.#.#..#..
+//! #L##.##.L#
+//! #.##.LL.LL
+//! #.###L#.##
+//! ..#.#.....
+//! #L######L#
+//! #.LL###L.L
+//! #.#L###.##
+//! #.#L.L#.##
+//! #LLL#LL.L#
+//! L.L.L..#..
+//! #LLL.##.L#
+//! #.LL.LL.LL
+//! #.LL#L#.##
+//! ..L.L.....
+//! #L#LLLL#L#
+//! #.LLLLLL.L
+//! #.#L#L#.##
+//! #.#L.L#.##
+//! #LLL#LL.L#
+//! L.#.L..#..
+//! #L##.##.L#
+//! #.#L.LL.LL
+//! #.#L#L#.##
+//! ..L.L.....
+//! #L#L##L#L#
+//! #.LLLLLL.L
+//! #.#L#L#.##
+//! ```
+//!
+//! At this point, something interesting happens: the chaos stabilizes and further applications of these rules cause no seats to change state! Once people stop moving around, you count 37 occupied seats.
+//!
+//! Simulate your seating area by applying the seating rules repeatedly until no seats change state. How many seats end up occupied?
+//!
+//! Your puzzle answer was 2310.
+//!
+//! ## --- Part Two ---
+//!
+//! As soon as people start to arrive, you realize your mistake. People don't just care about adjacent seats - they care about the first seat they can see in each of those eight directions!
+//!
+//! Now, instead of considering just the eight immediately adjacent seats, consider the first seat in each of those eight directions. For example, the empty seat below would see eight occupied seats:
+//!
+//! ```
+//! .......#.
+//! ...#.....
+//! .#.......
+//! .........
+//! ..#L....#
+//! ....#....
+//! .........
+//! #........
+//! ...#.....
+//! ```
+//!
+//! The leftmost empty seat below would only see one empty seat, but cannot see any of the occupied ones:
+//!
+//! ```
+//! .............
+//! .L.L.#.#.#.#.
+//! .............
+//! ```
+//!
+//! The empty seat below would see no occupied seats:
+//!
+//! ```
+//! .##.##.
+//! #.#.#.#
+//! ##...##
+//! ...L...
+//! ##...##
+//! #.#.#.#
+//! .##.##.
+//! ```
+//!
+//! Also, people seem to be more tolerant than you expected: it now takes five or more visible occupied seats for an occupied seat to become empty (rather than four or more from the previous rules). The other rules still apply: empty seats that see no occupied seats become occupied, seats matching no rule don't change, and floor never changes.
+//!
+//! Given the same starting layout as above, these new rules cause the seating area to shift around as follows:
+//!
+//! ```
+//! L.LL.LL.LL
+//! LLLLLLL.LL
+//! L.L.L..L..
+//! LLLL.LL.LL
+//! L.LL.LL.LL
+//! L.LLLLL.LL
+//! ..L.L.....
+//! LLLLLLLLLL
+//! L.LLLLLL.L
+//! L.LLLLL.LL
+//! #.##.##.##
+//! #######.##
+//! #.#.#..#..
+//! ####.##.##
+//! #.##.##.##
+//! #.#####.##
+//! ..#.#.....
+//! ##########
+//! #.######.#
+//! #.#####.##
+//! #.LL.LL.L#
+//! #LLLLLL.LL
+//! L.L.L..L..
+//! LLLL.LL.LL
+//! L.LL.LL.LL
+//! L.LLLLL.LL
+//! ..L.L.....
+//! LLLLLLLLL#
+//! #.LLLLLL.L
+//! #.LLLLL.L#
+//! #.L#.##.L#
+//! #L#####.LL
+//! L.#.#..#..
+//! ##L#.##.##
+//! #.##.#L.##
+//! #.#####.#L
+//! ..#.#.....
+//! LLL####LL#
+//! #.L#####.L
+//! #.L####.L#
+//! #.L#.L#.L#
+//! #LLLLLL.LL
+//! L.L.L..#..
+//! ##LL.LL.L#
+//! L.LL.LL.L#
+//! #.LLLLL.LL
+//! ..L.L.....
+//! LLLLLLLLL#
+//! #.LLLLL#.L
+//! #.L#LL#.L#
+//! #.L#.L#.L#
+//! #LLLLLL.LL
+//! L.L.L..#..
+//! ##L#.#L.L#
+//! L.L#.#L.L#
+//! #.L####.LL
+//! ..#.#.....
+//! LLL###LLL#
+//! #.LLLLL#.L
+//! #.L#LL#.L#
+//! #.L#.L#.L#
+//! #LLLLLL.LL
+//! L.L.L..#..
+//! ##L#.#L.L#
+//! L.L#.LL.L#
+//! #.LLLL#.LL
+//! ..#.L.....
+//! LLL###LLL#
+//! #.LLLLL#.L
+//! #.L#LL#.L#
+//! ```
+//!
+//! Again, at this point, people stop shifting around and the seating area reaches equilibrium. Once this occurs, you count 26 occupied seats.
+//!
+//! Given the new visibility method and the rule change for occupied seats becoming empty, once equilibrium is reached, how many seats end up occupied?
+//!
+//! Your puzzle answer was 2074.
+//!
+
+use std::iter;
+
+use aoc_common::{Answer, Color, Example, Frame, Frames, Grid, ParseError, Solution};
+
+mod seating;
+use seating::SeatingSystem;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[Example::doc(0, 1, "37"), Example::doc(0, 2, "26")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+
+    fn animate(input: &Self::Input, part: u8) -> Option<Frames<'_>> {
+        Some(Box::new(animate(input, part)))
+    }
+}
+
+/// occupied seats are green, empty seats are gray
+const PALETTE: &[(char, Color)] = &[('#', Color::Green), ('L', Color::Gray)];
+
+/// parses the seat layout into a grid of chars
+pub fn parse_input(contents: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(contents, "L#.")
+}
+
+/// counts the number of seats using pt1 rules after the seating has
+/// converged
+pub fn part1(input: &Grid<char>) -> i32 {
+    let mut seating = SeatingSystem::new(input);
+
+    loop {
+        let has_changed = seating.update();
+        if !has_changed {
+            break;
+        }
+    }
+
+    seating.count_seats()
+}
+
+/// counts the number of seats using pt2 rules after the seating has
+/// converged
+pub fn part2(input: &Grid<char>) -> i32 {
+    let mut seating = SeatingSystem::new(input);
+
+    loop {
+        let has_changed = seating.update2();
+        if !has_changed {
+            break;
+        }
+    }
+
+    seating.count_seats()
+}
+
+/// returns the seating after every round using the rules of `part`, until
+/// it stops changing
+pub fn animate(input: &Grid<char>, part: u8) -> impl Iterator<Item = Frame> {
+    let mut seating = SeatingSystem::new(input);
+    let mut round = 0;
+    let mut has_changed = true;
+
+    iter::from_fn(move || {
+        if !has_changed {
+            return None;
+        }
+
+        let caption = format!("round {}: {} occupied", round, seating.count_seats());
+        let frame = Frame::new(caption, seating.to_string()).with_palette(PALETTE);
+
+        has_changed = if part == 1 {
+            seating.update()
+        } else {
+            seating.update2()
+        };
+        round += 1;
+
+        Some(frame)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::load_input;
+
+    #[test]
+    fn test_sample_p1() {
+        let contents = load_input!("sample.txt").unwrap();
+        let input = parse_input(&contents).unwrap();
+
+        assert_eq!(part1(&input), 37);
+    }
+
+    #[test]
+    fn test_sample_p2() {
+        let contents = load_input!("sample.txt").unwrap();
+        let input = parse_input(&contents).unwrap();
+
+        assert_eq!(part2(&input), 26);
+    }
+
+    #[test]
+    fn test_animate() {
+        let contents = load_input!("sample.txt").unwrap();
+        let input = parse_input(&contents).unwrap();
+
+        let frames: Vec<Frame> = animate(&input, 1).collect();
+
+        assert_eq!(frames[0].text, contents.trim_end());
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[5].caption, "round 5: 37 occupied");
+    }
+}
@@ -9,76 +9,61 @@
 //! - `'.'` - is the floor
 //!
 
+use std::fmt;
+
+use aoc_common::grid::{Grid, ADJACENT};
+
 pub struct SeatingSystem {
-    grid: Vec<Vec<char>>,
+    grid: Grid<char>,
 }
 
 impl SeatingSystem {
     /// returns a new instance of `SeatingSystem` given a grid of seats
     /// where '#' is an occupied seat, 'L' is an empty seat, and '.' is the
     /// floor
-    pub fn new(grid: &[Vec<char>]) -> SeatingSystem {
-        SeatingSystem {
-            grid: grid.to_vec(),
-        }
+    pub fn new(grid: &Grid<char>) -> SeatingSystem {
+        SeatingSystem { grid: grid.clone() }
     }
 
     /// updates and applies the rules from pt1, returns a boolean that
     /// indicates if the grid has changed or not
     pub fn update(&mut self) -> bool {
-        let mut new_grid: Vec<Vec<char>> = self.grid.to_vec();
-        let mut has_changed = false;
-
-        #[allow(clippy::needless_range_loop)]
-        for i in 0..self.grid.len() {
-            for j in 0..self.grid[i].len() {
-                let seat = self.grid[i][j];
-                let num_adjacent = self.num_adjacent(i, j);
-
-                // rule 1
-                if seat == 'L' && num_adjacent == 0 {
-                    new_grid[i][j] = '#';
-                    has_changed = true;
-
-                // rule 2
-                } else if seat == '#' && num_adjacent >= 4 {
-                    new_grid[i][j] = 'L';
-                    has_changed = true;
-                }
-            }
-        }
-
-        if has_changed {
-            self.grid = new_grid;
-        }
-
-        has_changed
+        self.apply_rules(Self::num_adjacent, 4)
     }
 
     /// updates and applies the rules from pt2, returns a boolean that
     /// indicates if the seating has changed or not
     pub fn update2(&mut self) -> bool {
-        let mut new_grid: Vec<Vec<char>> = self.grid.to_vec();
+        self.apply_rules(Self::num_can_see, 5)
+    }
+
+    /// applies both rules to every seat at once, where `count_occupied`
+    /// counts the occupied seats that a seat cares about and `tolerance` is
+    /// how many of them it takes for someone to leave their seat
+    fn apply_rules(
+        &mut self,
+        count_occupied: fn(&Self, usize, usize) -> i32,
+        tolerance: i32,
+    ) -> bool {
         let mut has_changed = false;
 
-        #[allow(clippy::needless_range_loop)]
-        for i in 0..self.grid.len() {
-            for j in 0..self.grid[i].len() {
-                let seat = self.grid[i][j];
-                let num_can_see = self.num_can_see(i, j);
+        let new_grid = self.grid.map(|(row, col), &seat| {
+            let occupied = count_occupied(self, row, col);
 
+            match seat {
                 // rule 1
-                if seat == 'L' && num_can_see == 0 {
-                    new_grid[i][j] = '#';
+                'L' if occupied == 0 => {
                     has_changed = true;
-
+                    '#'
+                }
                 // rule 2
-                } else if seat == '#' && num_can_see >= 5 {
-                    new_grid[i][j] = 'L';
+                '#' if occupied >= tolerance => {
                     has_changed = true;
+                    'L'
                 }
+                _ => seat,
             }
-        }
+        });
 
         if has_changed {
             self.grid = new_grid;
@@ -90,109 +75,43 @@ impl SeatingSystem {
     /// returns the number of adjacent occupied seats next to the original
     /// seat (row, col)
     fn num_adjacent(&self, row: usize, col: usize) -> i32 {
-        let max_rows = self.grid.len() as i32;
-        let max_cols = self.grid[0].len() as i32;
-
-        let mut res = 0;
-
-        for i in -1..=1 {
-            for j in -1..=1 {
-                let row_num = (row as i32) + i;
-                let col_num = (col as i32) + j;
-
-                // only count seats that are 1) in bounds and 2) not the
-                // original seat
-                if !(i == 0 && j == 0)
-                    && (row_num < max_rows)
-                    && (row_num >= 0)
-                    && (col_num < max_cols)
-                    && (col_num >= 0)
-                    && (self.grid[row_num as usize][col_num as usize] == '#')
-                {
-                    res += 1;
-                }
-            }
-        }
-
-        res
+        self.grid
+            .neighbors8((row, col))
+            .filter(|&pos| self.grid[pos] == '#')
+            .count() as i32
     }
 
     /// returns the number of occupied seats that the current seat (row, col)
-    /// can see (in all 8 directions)
+    /// can see (in all 8 directions). the floor doesn't block the view
     fn num_can_see(&self, row: usize, col: usize) -> i32 {
-        let max_rows = self.grid.len() as i32;
-        let max_cols = self.grid[0].len() as i32;
-
-        let mut res: i32 = 0;
-
-        let dirs: Vec<(i32, i32)> = vec![
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-            (1, 0),
-            (1, -1),
-            (0, -1),
-        ];
-
-        let mut dirs_seen: Vec<bool> = vec![false; dirs.len()];
-
-        let mut layer = 1;
-
-        loop {
-            let seats: Vec<(usize, (i32, i32))> = dirs
-                .iter()
-                .map(|dir| (row as i32 + layer * dir.0, col as i32 + layer * dir.1))
-                .enumerate()
-                .filter(|(idx, (row_num, col_num))| {
-                    (!dirs_seen[*idx])
-                        && (*row_num >= 0)
-                        && (*row_num < max_rows)
-                        && (*col_num >= 0)
-                        && (*col_num < max_cols)
-                })
-                .collect();
-
-            for (idx, (i, j)) in &seats {
-                let seat = self.grid[*i as usize][*j as usize];
-                match seat {
-                    '#' => {
-                        dirs_seen[*idx] = true;
-                        res += 1;
-                    }
-                    'L' => {
-                        dirs_seen[*idx] = true;
-                    }
-                    _ => {}
-                };
-            }
-
-            if seats.is_empty() {
-                break;
-            }
-
-            layer += 1;
-        }
-
-        res
+        ADJACENT
+            .iter()
+            .filter_map(|&dir| {
+                self.grid
+                    .ray((row, col), dir)
+                    .map(|pos| self.grid[pos])
+                    .find(|&seat| seat != '.')
+            })
+            .filter(|&seat| seat == '#')
+            .count() as i32
     }
 
     /// a helper function that counts the number of occupied seats '#' in the
     /// grid
     pub fn count_seats(&self) -> i32 {
-        self.grid
-            .iter()
-            .map(|row| row.iter().filter(|&&ch| ch == '#').count() as i32)
-            .sum()
+        self.grid.iter().filter(|&(_, &ch)| ch == '#').count() as i32
     }
 
     /// helper function that prints the entire grid
     #[allow(dead_code)]
     pub fn print_grid(&self) {
-        for row in self.grid.iter() {
-            println!("{}", row.iter().collect::<String>());
-        }
+        println!("{}", self);
+    }
+}
+
+impl fmt::Display for SeatingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
     }
 }
 
@@ -202,11 +121,11 @@ mod tests {
 
     #[test]
     fn test_num_adjacent() {
-        let input: Vec<Vec<char>> = vec![
+        let input = Grid::from_rows(vec![
             vec!['L', 'L', '#', '#'],
             vec!['.', '#', '#', '#'],
             vec!['#', '#', '#', '#'],
-        ];
+        ]);
 
         let seating = SeatingSystem::new(&input);
 
@@ -218,13 +137,13 @@ mod tests {
 
     #[test]
     fn test_num_can_see() {
-        let input: Vec<Vec<char>> = vec![
+        let input = Grid::from_rows(vec![
             vec!['.', '.', '.', '#', '#'],
             vec!['.', '.', '.', '#', '#'],
             vec!['L', '#', '.', 'L', '#'],
             vec!['L', '#', '.', '#', 'L'],
             vec!['#', '.', '.', '#', 'L'],
-        ];
+        ]);
 
         let seating = SeatingSystem::new(&input);
 
@@ -1,151 +1,90 @@
-/// the `Ship` model. the orientation is in +degrees and the location is
-/// based on a cartesian coordinate system where E is (1, 0) and N is (0, 1)
+use aoc_common::{Direction, Move, Point2};
+
+/// the `Ship` model. the location is based on a cartesian coordinate system
+/// where E is (1, 0) and N is (0, 1), the waypoint is relative to the ship
 pub struct Ship {
-    orientation: i32,
-    location: (i32, i32),
-    waypoint: (i32, i32),
+    heading: Direction,
+    location: Point2,
+    waypoint: Point2,
 }
 
 impl Ship {
-    /// returns a new instance of `Ship` initialized with orientation 0 and
-    /// location (0, 0)
+    /// returns a new instance of `Ship` facing east at location (0, 0)
     pub fn new() -> Ship {
         Ship {
-            orientation: 0,
-            location: (0, 0),
-            waypoint: (10, 1),
+            heading: Direction::East,
+            location: Point2::ORIGIN,
+            waypoint: Point2::new(10, 1),
         }
     }
 
-    /// updates the ship's orientation and location based on a single action
-    /// valid actions are: N, S, E, W, L, R, F (based on p1)
-    pub fn handle_action_p1(&mut self, action: char, value: i32) {
+    /// updates the ship's heading or location based on a single action
+    /// (based on p1)
+    pub fn handle_action_p1(&mut self, action: Move) {
         match action {
-            'N' => {
-                self.location.1 += value;
-            }
-            'S' => {
-                self.location.1 -= value;
-            }
-            'E' => {
-                self.location.0 += value;
-            }
-            'W' => {
-                self.location.0 -= value;
-            }
-            'L' => {
-                self.orientation = (self.orientation + value) % 360;
-                if self.orientation < 0 {
-                    self.orientation += 360;
-                }
-            }
-            'R' => {
-                self.orientation = (self.orientation - value) % 360;
-                if self.orientation < 0 {
-                    self.orientation += 360;
-                }
-            }
-            'F' => {
-                let x = value * (self.orientation as f32).to_radians().cos() as i32;
-                let y = value * (self.orientation as f32).to_radians().sin() as i32;
-
-                self.location.0 += x;
-                self.location.1 += y;
-            }
-            _ => {}
+            Move::Go(direction, value) => self.location += direction.offset() * value,
+            Move::Turn(turn) => self.heading = self.heading.turn(turn),
+            Move::Forward(value) => self.location += self.heading.offset() * value,
         }
     }
 
-    /// updates the ship's location and the waypoints location based on a
-    /// single action valid actions are: N, S, E, W, L, R, F (based on p2)
-    pub fn handle_action_p2(&mut self, action: char, value: i32) {
+    /// updates the ship's location or the waypoint's location based on a
+    /// single action (based on p2)
+    pub fn handle_action_p2(&mut self, action: Move) {
         match action {
-            'N' => {
-                self.waypoint.1 += value;
-            }
-            'S' => {
-                self.waypoint.1 -= value;
-            }
-            'E' => {
-                self.waypoint.0 += value;
-            }
-            'W' => {
-                self.waypoint.0 -= value;
-            }
-            'L' => {
-                let mag = ((self.waypoint.0.pow(2) + self.waypoint.1.pow(2)) as f32).sqrt();
-                let angle = (self.waypoint.1 as f32).atan2(self.waypoint.0 as f32)
-                    + (value as f32).to_radians();
-
-                let x = (mag * angle.cos()).round();
-                let y = (mag * angle.sin()).round();
-
-                self.waypoint.0 = x as i32;
-                self.waypoint.1 = y as i32;
-            }
-            'R' => {
-                let mag = ((self.waypoint.0.pow(2) + self.waypoint.1.pow(2)) as f32).sqrt();
-                let angle = (self.waypoint.1 as f32).atan2(self.waypoint.0 as f32)
-                    - (value as f32).to_radians();
-
-                let x = (mag * angle.cos()).round();
-                let y = (mag * angle.sin()).round();
-
-                self.waypoint.0 = x as i32;
-                self.waypoint.1 = y as i32;
-            }
-            'F' => {
-                self.location.0 += value * self.waypoint.0;
-                self.location.1 += value * self.waypoint.1;
-            }
-            _ => {}
+            Move::Go(direction, value) => self.waypoint += direction.offset() * value,
+            Move::Turn(turn) => self.waypoint = self.waypoint.rotate(turn),
+            Move::Forward(value) => self.location += self.waypoint * value,
         }
     }
 
     /// returns the manhattan distance - the absolute values of its east/west
     /// position and its north/south position
-    pub fn get_manhattan_distance(&self) -> i32 {
-        self.location.0.abs() + self.location.1.abs()
+    pub fn get_manhattan_distance(&self) -> i64 {
+        self.location.manhattan(Point2::ORIGIN)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::Turn;
+
+    fn actions() -> Vec<Move> {
+        vec![
+            Move::Go(Direction::North, 2),
+            Move::Go(Direction::South, 1),
+            Move::Go(Direction::East, 1),
+            Move::Go(Direction::West, 3),
+            Move::Turn(Turn::Left),
+            Move::Turn(Turn::Around),
+            Move::Forward(3),
+        ]
+    }
 
     #[test]
     fn test_handle_action_p1() {
         let mut ship = Ship::new();
 
-        let actions: Vec<(char, i32)> = vec![
-            ('N', 2),
-            ('S', 1),
-            ('E', 1),
-            ('W', 3),
-            ('L', 90),
-            ('R', 180),
-            ('F', 3),
-        ];
-
-        let answers: Vec<(i32, (i32, i32))> = vec![
-            (0, (0, 2)),
-            (0, (0, 1)),
-            (0, (1, 1)),
-            (0, (-2, 1)),
-            (90, (-2, 1)),
-            (270, (-2, 1)),
-            (270, (-2, -2)),
+        let answers: Vec<(Direction, (i64, i64))> = vec![
+            (Direction::East, (0, 2)),
+            (Direction::East, (0, 1)),
+            (Direction::East, (1, 1)),
+            (Direction::East, (-2, 1)),
+            (Direction::North, (-2, 1)),
+            (Direction::South, (-2, 1)),
+            (Direction::South, (-2, -2)),
         ];
 
         // should be initialized to (0, 0)
-        assert_eq!(ship.location, (0, 0));
+        assert_eq!(ship.location, Point2::ORIGIN);
 
-        for (i, (action, value)) in actions.iter().enumerate() {
+        for (i, action) in actions().into_iter().enumerate() {
             let answer = answers[i];
 
-            ship.handle_action_p1(*action, *value);
-            assert_eq!(ship.orientation, answer.0);
-            assert_eq!(ship.location, answer.1);
+            ship.handle_action_p1(action);
+            assert_eq!(ship.heading, answer.0);
+            assert_eq!(ship.location, answer.1.into());
         }
     }
 
@@ -153,17 +92,7 @@ mod tests {
     fn test_handle_action_p2() {
         let mut ship = Ship::new();
 
-        let actions: Vec<(char, i32)> = vec![
-            ('N', 2),
-            ('S', 1),
-            ('E', 1),
-            ('W', 3),
-            ('L', 90),
-            ('R', 180),
-            ('F', 3),
-        ];
-
-        let answers: Vec<((i32, i32), (i32, i32))> = vec![
+        let answers: Vec<((i64, i64), (i64, i64))> = vec![
             ((0, 0), (10, 3)),
             ((0, 0), (10, 2)),
             ((0, 0), (11, 2)),
@@ -175,15 +104,15 @@ mod tests {
 
         // ship should be initialized to (0, 0)
         // and waypoint should be (10, 1)
-        assert_eq!(ship.location, (0, 0));
-        assert_eq!(ship.waypoint, (10, 1));
+        assert_eq!(ship.location, Point2::ORIGIN);
+        assert_eq!(ship.waypoint, Point2::new(10, 1));
 
-        for (i, (action, value)) in actions.iter().enumerate() {
+        for (i, action) in actions().into_iter().enumerate() {
             let answer = answers[i];
 
-            ship.handle_action_p2(*action, *value);
-            assert_eq!(ship.location, answer.0);
-            assert_eq!(ship.waypoint, answer.1);
+            ship.handle_action_p2(action);
+            assert_eq!(ship.location, answer.0.into());
+            assert_eq!(ship.waypoint, answer.1.into());
         }
     }
 
@@ -193,7 +122,7 @@ mod tests {
 
         assert_eq!(ship.get_manhattan_distance(), 0);
 
-        ship.location = (17, 8);
+        ship.location = Point2::new(17, 8);
         assert_eq!(ship.get_manhattan_distance(), 25);
     }
 }
@@ -0,0 +1,181 @@
+#![crate_name = "aoc_2020_day13"]
+
+use aoc_common::log::debug;
+use aoc_common::{math, parse, Answer, Example, ParseError, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    /// the earliest timestamp and every bus id, with 0 for the x's
+    type Input = (i64, Vec<i64>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::file("sample.txt", 1, "295"),
+        Example::file("sample.txt", 2, "1068781"),
+        Example::text("0\n17,x,13,19", 2, "3417"),
+        Example::text("0\n1789,37,47,1889", 2, "1202161486"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_notes(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let ids: Vec<i64> = input.1.iter().copied().filter(|id| *id != 0).collect();
+        part1(input.0, &ids).ok_or("there are no buses").into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(&input.1).ok_or("the buses never line up").into()
+    }
+}
+
+pub fn parse_input(contents: &str) -> Result<(i64, Vec<i64>), ParseError> {
+    let (early_time, bus_ids) = parse_notes(contents)?;
+
+    Ok((
+        early_time,
+        bus_ids.into_iter().filter(|id| *id != 0).collect(),
+    ))
+}
+
+/// parses the earliest timestamp and every bus id, with 0 for the x's
+fn parse_notes(contents: &str) -> Result<(i64, Vec<i64>), ParseError> {
+    let early_time = parse::next(contents, contents, contents.lines().next(), "timestamp")?;
+
+    Ok((
+        parse::number(contents, early_time)?,
+        parse_input2(contents)?,
+    ))
+}
+
+/// parses the input and returns a vector with the bus_ids, replaces 'x'
+/// with 0's. there has to be at least one bus, and ids are positive
+pub fn parse_input2(contents: &str) -> Result<Vec<i64>, ParseError> {
+    let bus_ids = parse::next(contents, contents, contents.lines().nth(1), "bus ids")?;
+
+    let ids = bus_ids
+        .split(',')
+        .map(|item| match item.trim() {
+            "x" => Ok(0),
+            id => match parse::number(contents, id)? {
+                id if id > 0 => Ok(id),
+                _ => Err(ParseError::at(contents, item, "expected a positive bus id")),
+            },
+        })
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+
+    if ids.iter().all(|&id| id == 0) {
+        return Err(ParseError::at(
+            contents,
+            bus_ids,
+            "expected at least one bus",
+        ));
+    }
+
+    Ok(ids)
+}
+
+/// multiplies the id of the first bus after `early_time` by the wait for
+/// it, `None` if there are no buses
+pub fn part1(early_time: i64, ids: &[i64]) -> Option<i64> {
+    let mut res: Vec<(i64, i64)> = Vec::new();
+
+    // for each bus_id
+    //   - take the greatest multiplier where bus_id * multiplier > early_time
+    //   - take the id with the closest multiple to early_time
+    //   - return the product of (bus_id * (closest_multiple - early_time))
+    for id in ids {
+        let mut multiplier = early_time / *id;
+        let remainder = early_time % *id;
+
+        if remainder == 0 {
+            return Some(*id * (*id * multiplier - early_time));
+        }
+
+        loop {
+            multiplier += 1;
+            let num = multiplier * *id;
+
+            if num > early_time {
+                res.push((num, multiplier));
+                debug!(
+                    "bus {} departs at {} (x{}), target: {}",
+                    *id, num, multiplier, early_time
+                );
+                break;
+            }
+        }
+    }
+
+    let (num, multiplier) = *res.iter().min()?;
+
+    Some((num / multiplier) * (num - early_time))
+}
+
+/// finds the earliest timestamp where every bus leaves as many minutes after
+/// it as its position in the list, `None` if they never do (ids with a
+/// common factor can't always line up). `ids` has a 0 for every x
+pub fn part2(ids: &[i64]) -> Option<i64> {
+    // bus i leaves at t + i, so t = -i (mod id)
+    let congruences: Vec<(i128, i128)> = ids
+        .iter()
+        .enumerate()
+        .filter(|(_, id)| **id != 0)
+        .map(|(i, id)| (-(i as i128), *id as i128))
+        .collect();
+
+    let (timestamp, _) = math::crt(&congruences)?;
+    Some(timestamp as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::load_input;
+
+    #[test]
+    fn test_sample_p1() {
+        let content = load_input!("sample.txt").unwrap();
+        let (early_time, bus_ids) = parse_input(&content).unwrap();
+        let res = part1(early_time, &bus_ids);
+
+        assert_eq!(res, Some(295));
+        assert_eq!(part1(early_time, &[]), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_input("939\nx,x").is_err());
+        assert!(parse_input("939\n7,0,13").is_err());
+        assert!(parse_input("939\n7,-13").is_err());
+    }
+
+    #[test]
+    fn test_sample_p2() {
+        let content = load_input!("sample.txt").unwrap();
+        let mut bus_ids = parse_input2(&content).unwrap();
+        let res = part2(&bus_ids);
+
+        assert_eq!(res, Some(1068781));
+
+        bus_ids = vec![17, 0, 13, 19];
+        assert_eq!(part2(&bus_ids), Some(3417));
+
+        bus_ids = vec![67, 7, 59, 61];
+        assert_eq!(part2(&bus_ids), Some(754018));
+
+        bus_ids = vec![67, 0, 7, 59, 61];
+        assert_eq!(part2(&bus_ids), Some(779210));
+
+        bus_ids = vec![67, 7, 0, 59, 61];
+        assert_eq!(part2(&bus_ids), Some(1261476));
+
+        bus_ids = vec![1789, 37, 47, 1889];
+        assert_eq!(part2(&bus_ids), Some(1202161486));
+
+        // bus 4 leaves on even minutes, so bus 6 can't leave a minute later
+        bus_ids = vec![4, 6];
+        assert_eq!(part2(&bus_ids), None);
+    }
+}
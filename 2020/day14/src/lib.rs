@@ -0,0 +1,118 @@
+#![crate_name = "aoc_2020_day14"]
+
+use aoc_common::{parse, Answer, Example, ParseError, Solution};
+
+mod system;
+use system::DockSystem;
+
+/// a single line of the initialization program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    /// `mask = X01...`
+    Mask(String),
+    /// `mem[address] = value`
+    Mem(u64, u64),
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::file("sample.txt", 1, "165"),
+        Example::file("sample2.txt", 2, "208"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn parse_input(contents: &str) -> Result<Vec<Instruction>, ParseError> {
+    contents
+        .lines()
+        .map(|line| {
+            let (target, value) = line
+                .split_once(" = ")
+                .ok_or_else(|| ParseError::at(contents, line, "expected '<target> = <value>'"))?;
+
+            if target == "mask" {
+                if value.len() != 36 {
+                    return Err(ParseError::at(contents, value, "expected a 36-bit mask"));
+                }
+                parse::row(contents, value, "01X")?;
+
+                return Ok(Instruction::Mask(value.to_string()));
+            }
+
+            let address = target
+                .strip_prefix("mem[")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .ok_or_else(|| {
+                    ParseError::at(contents, target, "expected 'mask' or 'mem[address]'")
+                })?;
+
+            Ok(Instruction::Mem(
+                parse::number(contents, address)?,
+                parse::number(contents, value)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn part1(program: &[Instruction]) -> u64 {
+    let mut sys = DockSystem::new();
+
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(mask) => sys.update_mask(mask),
+            Instruction::Mem(address, value) => sys.update_memory(*address, *value),
+        }
+    }
+
+    sys.get_sum()
+}
+
+pub fn part2(program: &[Instruction]) -> u64 {
+    let mut sys = DockSystem::new();
+
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(mask) => sys.update_mask(mask),
+            Instruction::Mem(address, value) => sys.update_memory2(*address, *value),
+        }
+    }
+
+    sys.get_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::load_input;
+
+    #[test]
+    fn test_sample_p1() {
+        let contents = load_input!("sample.txt").unwrap();
+        let res_p1 = part1(&parse_input(&contents).unwrap());
+
+        assert_eq!(res_p1, 165);
+    }
+
+    #[test]
+    fn test_sample_p2() {
+        let contents = load_input!("sample2.txt").unwrap();
+        let res_p2 = part2(&parse_input(&contents).unwrap());
+
+        assert_eq!(res_p2, 208);
+    }
+}
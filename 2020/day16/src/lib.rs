@@ -0,0 +1,381 @@
+#![crate_name = "aoc_2020_day16"]
+
+//! ## --- Day 16: Ticket Translation ---
+//!
+//! As you're walking to yet another connecting flight, you realize that one of the legs of your re-routed trip coming up is on a high-speed train. However, the train ticket you were given is in a language you don't understand. You should probably figure out what it says before you get to the train station after the next flight.
+//!
+//! Unfortunately, you can't actually read the words on the ticket. You can, however, read the numbers, and so you figure out the fields these tickets must have and the valid ranges for values in those fields.
+//!
+//! You collect the rules for ticket fields, the numbers on your ticket, and the numbers on other nearby tickets for the same train service (via the airport security cameras) together into a single document you can reference (your puzzle input).
+//!
+//! The rules for ticket fields specify a list of fields that exist somewhere on the ticket and the valid ranges of values for each field. For example, a rule like class: 1-3 or 5-7 means that one of the fields in every ticket is named class and can be any value in the ranges 1-3 or 5-7 (inclusive, such that 3 and 5 are both valid in this field, but 4 is not).
+//!
+//! Each ticket is represented by a single line of comma-separated values. The values are the numbers on the ticket in the order they appear; every ticket has the same format. For example, consider this ticket:
+//!
+//! ```
+//! .--------------------------------------------------------.
+//! | ????: 101 ?????: 102 ??????????: 103 ???: 104 |
+//! | |
+//! | ??: 301 ??: 302 ???????: 303 ??????? |
+//! | ??: 401 ??: 402 ???? ????: 403 ????????? |
+//! '--------------------------------------------------------'
+//! ```
+//!
+//! Here, ? represents text in a language you don't understand. This ticket might be represented as 101,102,103,104,301,302,303,401,402,403; of course, the actual train tickets you're looking at are much more complicated. In any case, you've extracted just the numbers in such a way that the first number is always the same specific field, the second number is always a different specific field, and so on - you just don't know what each position actually means!
+//!
+//! Start by determining which tickets are completely invalid; these are tickets that contain values which aren't valid for any field. Ignore your ticket for now.
+//!
+//! For example, suppose you have the following notes:
+//!
+//! ```
+//! class: 1-3 or 5-7
+//! row: 6-11 or 33-44
+//! seat: 13-40 or 45-50
+//!
+//! your ticket:
+//! 7,1,14
+//!
+//! nearby tickets:
+//! 7,3,47
+//! 40,4,50
+//! 55,2,20
+//! 38,6,12
+//! ```
+//!
+//! It doesn't matter which position corresponds to which field; you can identify invalid nearby tickets by considering only whether tickets contain values that are not valid for any field. In this example, the values on the first nearby ticket are all valid for at least one field. This is not true of the other three nearby tickets: the values 4, 55, and 12 are are not valid for any field. Adding together all of the invalid values produces your ticket scanning error rate: 4 + 55 + 12 = 71.
+//!
+//! Consider the validity of the nearby tickets you scanned. What is your ticket scanning error rate?
+//!
+//! Your puzzle answer was 25895.
+//!
+//! ## --- Part Two ---
+//!
+//! Now that you've identified which tickets contain invalid values, discard those tickets entirely. Use the remaining valid tickets to determine which field is which.
+//!
+//! Using the valid ranges for each field, determine what order the fields appear on the tickets. The order is consistent between all tickets: if seat is the third field, it is the third field on every ticket, including your ticket.
+//!
+//! For example, suppose you have the following notes:
+//!
+//! ```
+//! class: 0-1 or 4-19
+//! row: 0-5 or 8-19
+//! seat: 0-13 or 16-19
+//!
+//! your ticket:
+//! 11,12,13
+//!
+//! nearby tickets:
+//! 3,9,18
+//! 15,1,5
+//! 5,14,9
+//! ```
+//!
+//! Based on the nearby tickets in the above example, the first position must be row, the second position must be class, and the third position must be seat; you can conclude that in your ticket, class is 12, row is 11, and seat is 13.
+//!
+//! Once you work out which field is which, look for the six fields on your ticket that start with the word departure. What do you get if you multiply those six values together?
+//!
+//! Your puzzle answer was 5865723727753.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use aoc_common::log::{debug, log_enabled, Level};
+use aoc_common::{parse, Answer, Example, ParseError, RangeSet, Solution};
+
+/// the values a field can have, any number of ranges
+type Rule = RangeSet<i32>;
+type Rules = HashMap<String, Rule>;
+/// the rules, my ticket and the nearby tickets
+type Notes = (Rules, Vec<i32>, Vec<Vec<i32>>);
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::doc(1, 1, "71"),
+        // none of the example's fields start with departure
+        Example::doc(2, 2, "1"),
+        Example::text(
+            "departure class: 0-1 or 4-19\nrow: 0-5 or 8-19\ndeparture seat: 0-13 or 16-19\n\n\
+             your ticket:\n11,12,13\n\nnearby tickets:\n3,9,18\n15,1,5\n5,14,9\n",
+            2,
+            "156",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(&input.0, &input.2).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(&input.0, &input.1, &input.2).into()
+    }
+}
+
+pub fn parse_input(contents: &str) -> Result<Notes, ParseError> {
+    let mut records = parse::records(contents);
+    let mut record = |what| {
+        records
+            .next()
+            .ok_or_else(|| ParseError::new(format!("missing {}", what)))
+    };
+
+    let rules_record = record("rules")?;
+    let my_ticket_record = record("your ticket")?;
+    let nearby_tickets_record = record("nearby tickets")?;
+
+    // parses a comma separated ticket
+    let ticket = |line: &str| {
+        line.split(',')
+            .map(|n| parse::number(contents, n))
+            .collect::<Result<Vec<i32>, ParseError>>()
+    };
+
+    // populate rules
+    let rules = rules_record
+        .entries(":")
+        .map(|entry| {
+            let (name, ranges) = entry?;
+            Ok((name.to_string(), RangeSet::parse(contents, ranges)?))
+        })
+        .collect::<Result<Rules, ParseError>>()?;
+
+    // populate my ticket
+    let mut my_ticket_lines = section(contents, my_ticket_record, "your ticket:")?;
+    let my_ticket = ticket(parse::next(
+        contents,
+        my_ticket_record.text(),
+        my_ticket_lines.next(),
+        "ticket",
+    )?)?;
+
+    // populate nearby_tickets, which have the same fields as mine
+    let nearby_tickets = section(contents, nearby_tickets_record, "nearby tickets:")?
+        .map(|line| {
+            let nearby = ticket(line)?;
+            if nearby.len() != my_ticket.len() {
+                return Err(ParseError::at(
+                    contents,
+                    line,
+                    format!("expected {} fields like your ticket", my_ticket.len()),
+                ));
+            }
+            Ok(nearby)
+        })
+        .collect::<Result<Vec<Vec<i32>>, ParseError>>()?;
+
+    Ok((rules, my_ticket, nearby_tickets))
+}
+
+/// checks that a section of the notes starts with the `expected` header (e.g.
+/// "your ticket:") and returns the rest of its lines
+fn section<'a>(
+    contents: &str,
+    record: parse::Record<'a>,
+    expected: &str,
+) -> Result<std::str::Lines<'a>, ParseError> {
+    let (header, lines) = record.header();
+
+    if header.trim() != expected {
+        return Err(ParseError::at(
+            contents,
+            header,
+            format!("expected {:?}", expected),
+        ));
+    }
+
+    Ok(lines)
+}
+
+pub fn part1(rules: &Rules, nearby_tickets: &[Vec<i32>]) -> i32 {
+    let valid = any_rule(rules);
+
+    nearby_tickets
+        .iter()
+        .flatten()
+        .filter(|&&num| !valid.contains(num))
+        .sum()
+}
+
+/// multiplies the fields of my ticket that start with departure, once the
+/// valid nearby tickets tell which field is which
+pub fn part2(rules: &Rules, my_ticket: &[i32], nearby_tickets: &[Vec<i32>]) -> Result<u64, String> {
+    let fields = fields(rules, my_ticket.len(), nearby_tickets)?;
+
+    Ok(fields
+        .iter()
+        .filter(|(name, _)| name.starts_with("departure"))
+        .map(|(_, &field)| my_ticket[field] as u64)
+        .product())
+}
+
+/// works out the field (its position on a ticket) of each rule, from the
+/// nearby tickets that are valid
+fn fields<'a>(
+    rules: &'a Rules,
+    ticket_size: usize,
+    nearby_tickets: &[Vec<i32>],
+) -> Result<HashMap<&'a str, usize>, String> {
+    let valid = any_rule(rules);
+    let valid_tickets: Vec<&Vec<i32>> = nearby_tickets
+        .iter()
+        .filter(|ticket| ticket.iter().all(|&num| valid.contains(num)))
+        .collect();
+
+    let mut matrix: Vec<(&str, HashSet<usize>)> = rules
+        .iter()
+        .map(|(name, rule)| {
+            let fields = (0..ticket_size)
+                .filter(|&i| valid_tickets.iter().all(|ticket| rule.contains(ticket[i])))
+                .collect();
+
+            (name.as_str(), fields)
+        })
+        .collect();
+
+    log_matrix("the fields each rule matches", &matrix, ticket_size);
+    solve_matrix(&mut matrix)?;
+    log_matrix("the field of each rule", &matrix, ticket_size);
+
+    Ok(matrix
+        .into_iter()
+        .map(|(name, fields)| (name, *fields.iter().next().unwrap()))
+        .collect())
+}
+
+/// logs a row per rule with an X for every field it matches
+fn log_matrix(caption: &str, matrix: &[(&str, HashSet<usize>)], ticket_size: usize) {
+    if !log_enabled!(Level::Debug) {
+        return;
+    }
+
+    let mut out = format!("{}:", caption);
+    for (name, vals) in matrix {
+        out.push_str(&format!("\n{:<20}| ", name));
+
+        for i in 0..ticket_size {
+            if vals.contains(&i) {
+                out.push('X');
+            } else {
+                out.push('.');
+            }
+        }
+    }
+
+    debug!("{}", out);
+}
+
+/// narrows down the fields of each rule until every rule has one: a rule with
+/// a single field left takes it away from every other rule
+fn solve_matrix(matrix: &mut [(&str, HashSet<usize>)]) -> Result<(), String> {
+    let mut finished: HashSet<usize> = HashSet::new();
+
+    while finished.len() < matrix.len() {
+        if let Some((name, _)) = matrix.iter().find(|(_, fields)| fields.is_empty()) {
+            return Err(format!("no field matches {}", name));
+        }
+
+        // the rules that are down to a single field, but haven't taken it
+        // away from the others yet
+        let singles: Vec<usize> = (0..matrix.len())
+            .filter(|i| !finished.contains(i) && matrix[*i].1.len() == 1)
+            .collect();
+
+        if singles.is_empty() {
+            return Err("the fields can't be told apart".to_string());
+        }
+
+        for i in singles {
+            let field = *matrix[i].1.iter().next().unwrap();
+
+            for (j, (_, fields)) in matrix.iter_mut().enumerate() {
+                if j != i {
+                    fields.remove(&field);
+                }
+            }
+
+            finished.insert(i);
+        }
+    }
+
+    Ok(())
+}
+
+/// the values that are valid for at least one of the rules
+fn any_rule(rules: &Rules) -> Rule {
+    rules
+        .values()
+        .fold(RangeSet::new(), |valid, rule| valid.union(rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::load_input;
+
+    #[test]
+    fn test_sample_p1() {
+        let (rules, _, nearby_tickets) = parse_input(&load_input!("sample.txt").unwrap()).unwrap();
+        assert_eq!(part1(&rules, &nearby_tickets), 71);
+    }
+
+    #[test]
+    fn test_any_number_of_ranges() {
+        let notes = [
+            "class: 1-3",
+            "row: 6-11 or 33-44 or 50-52",
+            "",
+            "your ticket:",
+            "7,1,14",
+            "",
+            "nearby tickets:",
+            "2,51,12",
+            "40,4,53",
+        ]
+        .join("\n");
+        let (rules, _, nearby_tickets) = parse_input(&notes).unwrap();
+
+        assert_eq!(rules["row"].to_string(), "6-11 or 33-44 or 50-52");
+        assert_eq!(part1(&rules, &nearby_tickets), 12 + 4 + 53);
+    }
+
+    #[test]
+    fn test_fields() {
+        let notes = [
+            "class: 0-1 or 4-19",
+            "row: 0-5 or 8-19",
+            "seat: 0-13 or 16-19",
+            "",
+            "your ticket:",
+            "11,12,13",
+            "",
+            "nearby tickets:",
+            "3,9,18",
+            "15,1,5",
+            "5,14,9",
+        ]
+        .join("\n");
+        let (rules, my_ticket, nearby_tickets) = parse_input(&notes).unwrap();
+
+        let fields = fields(&rules, my_ticket.len(), &nearby_tickets).unwrap();
+        assert_eq!(
+            fields,
+            HashMap::from([("row", 0), ("class", 1), ("seat", 2)])
+        );
+
+        // only one of the sample's nearby tickets is valid, which is enough
+        let (rules, my_ticket, nearby_tickets) =
+            parse_input(&load_input!("sample.txt").unwrap()).unwrap();
+        assert_eq!(part2(&rules, &my_ticket, &nearby_tickets), Ok(1));
+
+        // without any valid tickets every rule matches every field
+        assert!(part2(&rules, &my_ticket, &[]).is_err());
+        assert_eq!(part2(&HashMap::new(), &my_ticket, &nearby_tickets), Ok(1));
+    }
+}
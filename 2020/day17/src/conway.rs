@@ -0,0 +1,425 @@
+//! # Conway
+//!
+//! This module contains a sparse Conway cube engine that works in any number
+//! of dimensions `N`. Only the coordinates of the active cubes are stored, so
+//! the pocket dimension can grow for any number of cycles without allocating
+//! space for it up front.
+//!
+//! A cube's neighbors are all of the cubes whose coordinates differ by at
+//! most 1 in every dimension (`3^N - 1` of them). Whether a cube is active
+//! after a cycle is decided by the [`Rules`].
+//!
+//! The initial state is a 2D slice at 0 in every other dimension, so the
+//! state is always mirrored across those dimensions (the `z = -1` layer looks
+//! exactly like the `z = 1` layer). [`Conway::with_symmetry`] takes advantage
+//! of that and only simulates the cubes that are at `>= 0` in them.
+
+use std::collections::{HashMap, HashSet};
+
+/// the coordinates of a cube
+pub type Cell<const N: usize> = [i32; N];
+
+/// the number of active neighbors that make an inactive cube become active
+/// (birth), and that keep an active cube active (survival)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rules {
+    /// creates new rules. `birth` can't contain 0, since that would activate
+    /// infinitely many cubes
+    pub fn new(birth: &[usize], survival: &[usize]) -> Rules {
+        assert!(
+            !birth.contains(&0),
+            "cubes with no active neighbors can't become active"
+        );
+
+        Rules {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        }
+    }
+
+    /// the rules from the puzzle: an active cube stays active with 2 or 3
+    /// active neighbors, and an inactive cube becomes active with exactly 3
+    pub fn conway() -> Rules {
+        Rules::new(&[3], &[2, 3])
+    }
+
+    /// returns whether a cube is active after a cycle
+    fn next(&self, active: bool, neighbors: usize) -> bool {
+        if active {
+            self.survival.contains(&neighbors)
+        } else {
+            self.birth.contains(&neighbors)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Conway<const N: usize> {
+    active: HashSet<Cell<N>>,
+    rules: Rules,
+    symmetric: bool,
+    offsets: Vec<Cell<N>>,
+}
+
+impl<const N: usize> Conway<N> {
+    /// creates a new pocket dimension from a set of active cubes
+    pub fn new(active: impl IntoIterator<Item = Cell<N>>, rules: Rules) -> Conway<N> {
+        Conway {
+            active: active.into_iter().collect(),
+            rules,
+            symmetric: false,
+            offsets: offsets(),
+        }
+    }
+
+    /// creates a new pocket dimension from a 2D slice of cubes (`true` is
+    /// active) at 0 in every dimension after the first two
+    pub fn from_slice(slice: &[Vec<bool>], rules: Rules) -> Conway<N> {
+        assert!(N >= 2, "a slice needs at least 2 dimensions");
+
+        let active = slice.iter().enumerate().flat_map(|(x, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &cube)| cube)
+                .map(move |(y, _)| {
+                    let mut cell = [0; N];
+                    cell[0] = x as i32;
+                    cell[1] = y as i32;
+                    cell
+                })
+        });
+
+        Conway::new(active, rules)
+    }
+
+    /// only simulates the cubes at `>= 0` in every dimension after the first
+    /// two, and mirrors them to get the rest. this is only correct if the
+    /// state is already mirrored across those dimensions, e.g. when it was
+    /// created by [`Conway::from_slice`]
+    pub fn with_symmetry(mut self) -> Conway<N> {
+        assert!(
+            N >= 2,
+            "there's nothing to mirror in less than 2 dimensions"
+        );
+        assert!(
+            self.active.iter().all(|cell| mirror_images(*cell)
+                .iter()
+                .all(|image| self.active.contains(image))),
+            "the state isn't mirrored across the extra dimensions"
+        );
+
+        self.active.retain(|cell| cell[2..].iter().all(|&c| c >= 0));
+        self.symmetric = true;
+        self
+    }
+
+    /// simulates a single cycle
+    pub fn step(&mut self) {
+        // count the active neighbors of every cube next to an active cube
+        let mut counts: HashMap<Cell<N>, usize> = HashMap::new();
+
+        for cell in &self.active {
+            for image in self.images(*cell) {
+                for offset in &self.offsets {
+                    let neighbor = add(image, *offset);
+
+                    if self.is_canonical(&neighbor) {
+                        *counts.entry(neighbor).or_default() += 1;
+                    }
+                }
+            }
+        }
+
+        let mut next: HashSet<Cell<N>> = counts
+            .iter()
+            .filter(|(cell, &count)| self.rules.next(self.active.contains(*cell), count))
+            .map(|(cell, _)| *cell)
+            .collect();
+
+        // active cubes without any active neighbors were never counted
+        if self.rules.survival.contains(&0) {
+            next.extend(
+                self.active
+                    .iter()
+                    .filter(|cell| !counts.contains_key(*cell)),
+            );
+        }
+
+        self.active = next;
+    }
+
+    /// simulates `cycles` cycles
+    pub fn run(&mut self, cycles: usize) {
+        for _ in 0..cycles {
+            self.step();
+        }
+    }
+
+    /// returns the number of active cubes
+    pub fn count_active(&self) -> usize {
+        self.active
+            .iter()
+            .map(|cell| {
+                if self.symmetric {
+                    1 << cell[2..].iter().filter(|&&c| c != 0).count()
+                } else {
+                    1
+                }
+            })
+            .sum()
+    }
+
+    /// returns whether the cube at `cell` is active
+    pub fn is_active(&self, cell: Cell<N>) -> bool {
+        self.active.contains(&self.canonical(cell))
+    }
+
+    /// returns the number of active neighbors of the cube at `cell`
+    pub fn active_neighbors(&self, cell: Cell<N>) -> usize {
+        self.offsets
+            .iter()
+            .filter(|offset| self.is_active(add(cell, **offset)))
+            .count()
+    }
+
+    /// returns the coordinates of every active cube
+    pub fn active_cells(&self) -> impl Iterator<Item = Cell<N>> + '_ {
+        self.active.iter().flat_map(move |cell| self.images(*cell))
+    }
+
+    /// renders the active cubes the way the puzzle does: a grid of `#`
+    /// (active) and `.` for every slice through the dimensions after the
+    /// first two that has an active cube, labelled `z=.., w=..`
+    pub fn render(&self) -> String {
+        let cells: Vec<Cell<N>> = self.active_cells().collect();
+
+        if cells.is_empty() {
+            return String::new();
+        }
+
+        let range = |dim: usize| {
+            let min = cells.iter().map(|cell| cell[dim]).min().unwrap();
+            let max = cells.iter().map(|cell| cell[dim]).max().unwrap();
+            min..=max
+        };
+
+        // the slices are ordered by the last dimension first, like the puzzle
+        let mut slices: Vec<&[i32]> = cells.iter().map(|cell| &cell[2..]).collect();
+        slices.sort_unstable_by(|a, b| a.iter().rev().cmp(b.iter().rev()));
+        slices.dedup();
+
+        slices
+            .iter()
+            .map(|slice| {
+                let label = slice
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| format!("{}={}", dimension_name(i + 2), c))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                let rows = range(0)
+                    .map(|x| {
+                        range(1)
+                            .map(|y| {
+                                let mut cell = [0; N];
+                                cell[0] = x;
+                                cell[1] = y;
+                                cell[2..].copy_from_slice(slice);
+                                if self.is_active(cell) {
+                                    '#'
+                                } else {
+                                    '.'
+                                }
+                            })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+
+                if label.is_empty() {
+                    rows
+                } else {
+                    format!("{}\n{}", label, rows)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    /// returns the cell that `cell` is stored as, mirrored to `>= 0` in
+    /// every dimension after the first two
+    fn canonical(&self, mut cell: Cell<N>) -> Cell<N> {
+        if self.symmetric {
+            cell[2..].iter_mut().for_each(|c| *c = c.abs());
+        }
+        cell
+    }
+
+    fn is_canonical(&self, cell: &Cell<N>) -> bool {
+        !self.symmetric || cell[2..].iter().all(|&c| c >= 0)
+    }
+
+    /// returns every cell that the stored `cell` stands for
+    fn images(&self, cell: Cell<N>) -> Vec<Cell<N>> {
+        if self.symmetric {
+            mirror_images(cell)
+        } else {
+            vec![cell]
+        }
+    }
+}
+
+/// returns `cell` and all of its distinct mirror images across the dimensions
+/// after the first two
+fn mirror_images<const N: usize>(cell: Cell<N>) -> Vec<Cell<N>> {
+    let mut images = vec![cell];
+
+    for dim in (2..N).filter(|&dim| cell[dim] != 0) {
+        let mirrored: Vec<Cell<N>> = images
+            .iter()
+            .map(|image| {
+                let mut image = *image;
+                image[dim] = -image[dim];
+                image
+            })
+            .collect();
+        images.extend(mirrored);
+    }
+
+    images
+}
+
+/// returns the name of dimension `dim` (`x`, `y`, `z`, `w`, then `d4`, ...)
+fn dimension_name(dim: usize) -> String {
+    match dim {
+        0 => "x".to_string(),
+        1 => "y".to_string(),
+        2 => "z".to_string(),
+        3 => "w".to_string(),
+        _ => format!("d{}", dim),
+    }
+}
+
+/// adds two coordinates
+fn add<const N: usize>(mut a: Cell<N>, b: Cell<N>) -> Cell<N> {
+    a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+    a
+}
+
+/// returns the offsets to all `3^N - 1` neighbors
+fn offsets<const N: usize>() -> Vec<Cell<N>> {
+    let mut offsets = vec![[0; N]];
+
+    for dim in 0..N {
+        offsets = offsets
+            .into_iter()
+            .flat_map(|offset| {
+                (-1..=1).map(move |d| {
+                    let mut offset = offset;
+                    offset[dim] = d;
+                    offset
+                })
+            })
+            .collect();
+    }
+
+    offsets.retain(|offset| offset.iter().any(|&d| d != 0));
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(offsets::<2>().len(), 8);
+        assert_eq!(offsets::<3>().len(), 26);
+        assert_eq!(offsets::<4>().len(), 80);
+    }
+
+    #[test]
+    fn test_blinker() {
+        // the 2D game of life, a blinker flips between horizontal and vertical
+        let mut conway = Conway::<2>::new([[1, 0], [1, 1], [1, 2]], Rules::conway());
+
+        conway.step();
+        let mut cells: Vec<Cell<2>> = conway.active_cells().collect();
+        cells.sort_unstable();
+        assert_eq!(cells, vec![[0, 1], [1, 1], [2, 1]]);
+
+        conway.step();
+        assert!(conway.is_active([1, 0]) && conway.is_active([1, 2]));
+        assert_eq!(conway.count_active(), 3);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let slice = vec![
+            vec![false, true, false],
+            vec![false, false, true],
+            vec![true, true, true],
+        ];
+
+        for cycles in 0..4 {
+            let mut full = Conway::<4>::from_slice(&slice, Rules::conway());
+            let mut mirrored = Conway::<4>::from_slice(&slice, Rules::conway()).with_symmetry();
+
+            full.run(cycles);
+            mirrored.run(cycles);
+
+            assert_eq!(full.count_active(), mirrored.count_active());
+
+            let mut cells: Vec<Cell<4>> = mirrored.active_cells().collect();
+            cells.sort_unstable();
+            let mut expected: Vec<Cell<4>> = full.active_cells().collect();
+            expected.sort_unstable();
+            assert_eq!(cells, expected);
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        // with survival on 0 neighbors, a lone cube never dies
+        let mut conway = Conway::<3>::new([[0, 0, 0]], Rules::new(&[3], &[0]));
+        conway.run(3);
+        assert_eq!(conway.count_active(), 1);
+
+        let mut conway = Conway::<3>::new([[0, 0, 0]], Rules::conway());
+        conway.step();
+        assert_eq!(conway.count_active(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "isn't mirrored")]
+    fn test_symmetry_needs_mirrored_state() {
+        Conway::<3>::new([[0, 0, 1]], Rules::conway()).with_symmetry();
+    }
+
+    #[test]
+    fn test_render() {
+        let slice = vec![
+            vec![false, true, false],
+            vec![false, false, true],
+            vec![true, true, true],
+        ];
+
+        let mut conway = Conway::<3>::from_slice(&slice, Rules::conway()).with_symmetry();
+        assert_eq!(conway.render(), "z=0\n.#.\n..#\n###");
+
+        // the first cycle from the puzzle, trimmed to the active cubes
+        conway.step();
+        assert_eq!(
+            conway.render(),
+            "z=-1\n#..\n..#\n.#.\n\nz=0\n#.#\n.##\n.#.\n\nz=1\n#..\n..#\n.#."
+        );
+
+        assert_eq!(Conway::<2>::new([[0, 0]], Rules::conway()).render(), "#");
+    }
+}
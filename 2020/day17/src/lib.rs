@@ -0,0 +1,562 @@
+#![crate_name = "aoc_2020_day17"]
+
+//! ## --- Day 17: Conway Cubes ---
+//!
+//! As your flight slowly drifts through the sky, the Elves at the Mythical Information Bureau at the North Pole contact you. They'd like some help debugging a malfunctioning experimental energy source aboard one of their super-secret imaging satellites.
+//!
+//! The experimental energy source is based on cutting-edge technology: a set of Conway Cubes contained in a pocket dimension! When you hear it's having problems, you can't help but agree to take a look.
+//!
+//! The pocket dimension contains an infinite 3-dimensional grid. At every integer 3-dimensional coordinate (x,y,z), there exists a single cube which is either active or inactive.
+//!
+//! In the initial state of the pocket dimension, almost all cubes start inactive. The only exception to this is a small flat region of cubes (your puzzle input); the cubes in this region start in the specified active (#) or inactive (.) state.
+//!
+//! The energy source then proceeds to boot up by executing six cycles.
+//!
+//! Each cube only ever considers its neighbors: any of the 26 other cubes where any of their coordinates differ by at most 1. For example, given the cube at x=1,y=2,z=3, its neighbors include the cube at x=2,y=2,z=2, the cube at x=0,y=2,z=3, and so on.
+//!
+//! During a cycle, all cubes simultaneously change their state according to the following rules:
+//!
+//! If a cube is active and exactly 2 or 3 of its neighbors are also active, the cube remains active. Otherwise, the cube becomes inactive.
+//! If a cube is inactive but exactly 3 of its neighbors are active, the cube becomes active. Otherwise, the cube remains inactive.
+//! The engineers responsible for this experimental energy source would like you to simulate the pocket dimension and determine what the configuration of cubes should be at the end of the six-cycle boot process.
+//!
+//! For example, consider the following initial state:
+//!
+//! ```
+//! .#.
+//! ..#
+//! ###
+//! ```
+//!
+//! Even though the pocket dimension is 3-dimensional, this initial state represents a small 2-dimensional slice of it. (In particular, this initial state defines a 3x3x1 region of the 3-dimensional space.)
+//!
+//! Simulating a few cycles from this initial state produces the following configurations, where the result of each cycle is shown layer-by-layer at each given z coordinate (and the frame of view follows the active cells in each cycle):
+//!
+//! ```
+//! Before any cycles:
+//!
+//! z=0
+//! .#.
+//! ..#
+//! ###
+//!
+//!
+//! After 1 cycle:
+//!
+//! z=-1
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=0
+//! #.#
+//! .##
+//! .#.
+//!
+//! z=1
+//! #..
+//! ..#
+//! .#.
+//!
+//!
+//! After 2 cycles:
+//!
+//! z=-2
+//! .....
+//! .....
+//! ..#..
+//! .....
+//! .....
+//!
+//! z=-1
+//! ..#..
+//! .#..#
+//! ....#
+//! .#...
+//! .....
+//!
+//! z=0
+//! ##...
+//! ##...
+//! #....
+//! ....#
+//! .###.
+//!
+//! z=1
+//! ..#..
+//! .#..#
+//! ....#
+//! .#...
+//! .....
+//!
+//! z=2
+//! .....
+//! .....
+//! ..#..
+//! .....
+//! .....
+//!
+//!
+//! After 3 cycles:
+//!
+//! z=-2
+//! .......
+//! .......
+//! ..##...
+//! ..###..
+//! .......
+//! .......
+//! .......
+//!
+//! z=-1
+//! ..#....
+//! ...#...
+//! #......
+//! .....##
+//! .#...#.
+//! ..#.#..
+//! ...#...
+//!
+//! z=0
+//! ...#...
+//! .......
+//! #......
+//! .......
+//! .....##
+//! .##.#..
+//! ...#...
+//!
+//! z=1
+//! ..#....
+//! ...#...
+//! #......
+//! .....##
+//! .#...#.
+//! ..#.#..
+//! ...#...
+//!
+//! z=2
+//! .......
+//! .......
+//! ..##...
+//! ..###..
+//! .......
+//! .......
+//! .......
+//! ```
+//!
+//! After the full six-cycle boot process completes, 112 cubes are left in the active state.
+//!
+//! Starting with your given initial configuration, simulate six cycles. How many cubes are left in the active state after the sixth cycle?
+//!
+//! Your puzzle answer was 338.
+//!
+//! ## --- Part Two ---
+//!
+//! For some reason, your simulated results don't match what the experimental energy source engineers expected. Apparently, the pocket dimension actually has four spatial dimensions, not three.
+//!
+//! The pocket dimension contains an infinite 4-dimensional grid. At every integer 4-dimensional coordinate (x,y,z,w), there exists a single cube (really, a hypercube) which is still either active or inactive.
+//!
+//! Each cube only ever considers its neighbors: any of the 80 other cubes where any of their coordinates differ by at most 1. For example, given the cube at x=1,y=2,z=3,w=4, its neighbors include the cube at x=2,y=2,z=3,w=3, the cube at x=0,y=2,z=3,w=4, and so on.
+//!
+//! The initial state of the pocket dimension still consists of a small flat region of cubes. Furthermore, the same rules for cycle updating still apply: during each cycle, consider the number of active neighbors of each cube.
+//!
+//! For example, consider the same initial state as in the example above. Even though the pocket dimension is 4-dimensional, this initial state represents a small 2-dimensional slice of it. (In particular, this initial state defines a 3x3x1x1 region of the 4-dimensional space.)
+//!
+//! Simulating a few cycles from this initial state produces the following configurations, where the result of each cycle is shown layer-by-layer at each given z and w coordinate:
+//!
+//! ```
+//! Before any cycles:
+//!
+//! z=0, w=0
+//! .#.
+//! ..#
+//! ###
+//!
+//!
+//! After 1 cycle:
+//!
+//! z=-1, w=-1
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=0, w=-1
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=1, w=-1
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=-1, w=0
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=0, w=0
+//! #.#
+//! .##
+//! .#.
+//!
+//! z=1, w=0
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=-1, w=1
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=0, w=1
+//! #..
+//! ..#
+//! .#.
+//!
+//! z=1, w=1
+//! #..
+//! ..#
+//! .#.
+//!
+//!
+//! After 2 cycles:
+//!
+//! z=-2, w=-2
+//! .....
+//! .....
+//! ..#..
+//! .....
+//! .....
+//!
+//! z=-1, w=-2
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=0, w=-2
+//! ###..
+//! ##.##
+//! #...#
+//! .#..#
+//! .###.
+//!
+//! z=1, w=-2
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=2, w=-2
+//! .....
+//! .....
+//! ..#..
+//! .....
+//! .....
+//!
+//! z=-2, w=-1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=-1, w=-1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=0, w=-1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=1, w=-1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=2, w=-1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=-2, w=0
+//! ###..
+//! ##.##
+//! #...#
+//! .#..#
+//! .###.
+//!
+//! z=-1, w=0
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=0, w=0
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=1, w=0
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=2, w=0
+//! ###..
+//! ##.##
+//! #...#
+//! .#..#
+//! .###.
+//!
+//! z=-2, w=1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=-1, w=1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=0, w=1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=1, w=1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=2, w=1
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=-2, w=2
+//! .....
+//! .....
+//! ..#..
+//! .....
+//! .....
+//!
+//! z=-1, w=2
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=0, w=2
+//! ###..
+//! ##.##
+//! #...#
+//! .#..#
+//! .###.
+//!
+//! z=1, w=2
+//! .....
+//! .....
+//! .....
+//! .....
+//! .....
+//!
+//! z=2, w=2
+//! .....
+//! .....
+//! ..#..
+//! .....
+//! .....
+//! ```
+//!
+//! After the full six-cycle boot process completes, 848 cubes are left in the active state.
+//!
+//! Starting with your given initial configuration, simulate six cycles in a 4-dimensional space. How many cubes are left in the active state after the sixth cycle?
+//!
+//! Your puzzle answer was 2440.
+
+use std::iter;
+
+use aoc_common::{parse, Answer, Color, Example, Frame, Frames, ParseError, Solution};
+
+pub mod conway;
+use conway::{Conway, Rules};
+
+/// 1-D type
+type Line = Vec<bool>;
+type Layer = Vec<Line>;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Layer;
+
+    const EXAMPLES: &'static [Example] = &[Example::doc(0, 1, "112"), Example::doc(0, 2, "848")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input, 6).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input, 6).into()
+    }
+
+    fn animate(input: &Self::Input, part: u8) -> Option<Frames<'_>> {
+        Some(match part {
+            1 => Box::new(animate::<3>(input, 6)),
+            _ => Box::new(animate::<4>(input, 6)),
+        })
+    }
+}
+
+pub fn parse_input(contents: &str) -> Result<Layer, ParseError> {
+    contents
+        .lines()
+        .map(|line| {
+            let row = parse::row(contents, line, ".#")?;
+            Ok(row.into_iter().map(|ch| ch == '#').collect())
+        })
+        .collect()
+}
+
+/// simulates the cube growing in 3 dimensions
+pub fn part1(layer: &Layer, cycles: usize) -> usize {
+    simulate::<3>(layer, cycles)
+}
+
+/// simulates the cube growing in 4 dimensions
+pub fn part2(layer: &Layer, cycles: usize) -> usize {
+    simulate::<4>(layer, cycles)
+}
+
+/// simulates `cycles` cycles in `N` dimensions starting from the initial
+/// `layer` and returns the number of active cubes
+pub fn simulate<const N: usize>(layer: &Layer, cycles: usize) -> usize {
+    let mut conway = Conway::<N>::from_slice(layer, Rules::conway()).with_symmetry();
+    conway.run(cycles);
+    conway.count_active()
+}
+
+/// returns the pocket dimension after each of `cycles` cycles in `N`
+/// dimensions, starting with the initial `layer`
+pub fn animate<const N: usize>(layer: &Layer, cycles: usize) -> impl Iterator<Item = Frame> {
+    let mut conway = Conway::<N>::from_slice(layer, Rules::conway()).with_symmetry();
+    let mut cycle = 0;
+
+    iter::from_fn(move || {
+        if cycle > cycles {
+            return None;
+        }
+
+        if cycle > 0 {
+            conway.step();
+        }
+
+        let caption = format!("cycle {}: {} active", cycle, conway.count_active());
+        cycle += 1;
+
+        Some(Frame::new(caption, conway.render()).with_palette(&[('#', Color::Green)]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::load_input;
+
+    #[test]
+    fn test_sample_p1() {
+        let input = parse_input(&load_input!("sample.txt").unwrap()).unwrap();
+        assert_eq!(part1(&input, 6), 112);
+    }
+
+    #[test]
+    fn test_sample_p2() {
+        let input = parse_input(&load_input!("sample.txt").unwrap()).unwrap();
+        assert_eq!(part2(&input, 6), 848);
+    }
+
+    #[test]
+    fn test_animate() {
+        let input = parse_input(&load_input!("sample.txt").unwrap()).unwrap();
+        let frames: Vec<Frame> = animate::<3>(&input, 6).collect();
+
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames[0].text, "z=0\n.#.\n..#\n###");
+        assert_eq!(frames[6].caption, "cycle 6: 112 active");
+    }
+
+    #[test]
+    fn test_get_active_neighbors() {
+        let grid: Vec<Layer> = vec![
+            vec![
+                vec![true, false, false],
+                vec![false, false, true],
+                vec![false, true, false],
+            ],
+            vec![
+                vec![true, false, true],
+                vec![false, true, true],
+                vec![false, true, false],
+            ],
+            vec![
+                vec![true, false, false],
+                vec![false, false, true],
+                vec![false, true, false],
+            ],
+        ];
+
+        let mut active = Vec::new();
+        for (z, layer) in grid.iter().enumerate() {
+            for (x, line) in layer.iter().enumerate() {
+                for (y, &cube) in line.iter().enumerate() {
+                    if cube {
+                        active.push([z as i32, x as i32, y as i32]);
+                    }
+                }
+            }
+        }
+        let conway = Conway::<3>::new(active, Rules::conway());
+
+        assert_eq!(conway.active_neighbors([1, 1, 1]), 10);
+        assert_eq!(conway.active_neighbors([0, 1, 1]), 8);
+        assert_eq!(conway.active_neighbors([0, 0, 1]), 6);
+        assert_eq!(conway.active_neighbors([2, 0, 1]), 6);
+        assert_eq!(conway.active_neighbors([2, 2, 2]), 5);
+    }
+}
@@ -0,0 +1,69 @@
+#![crate_name = "aoc_2020_day20"]
+#![allow(clippy::all)]
+#![allow(dead_code, unused_variables, unused_mut)]
+
+use std::collections::HashMap;
+
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+mod tile;
+use tile::Tile;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = HashMap<i32, Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// parses the input file into a HashMap<i32, Tile> where the id is the the
+/// key and the `Tile` type is the value
+pub fn parse_input(contents: &str) -> Result<HashMap<i32, Tile>, ParseError> {
+    parse::records(contents)
+        .map(|block| {
+            let (header, lines) = block.header();
+
+            let id = header
+                .strip_prefix("Tile ")
+                .and_then(|rest| rest.trim_end().strip_suffix(':'))
+                .ok_or_else(|| ParseError::at(contents, header, "expected 'Tile <id>:'"))?;
+
+            let tile = lines
+                .map(|line| parse::row(contents, line.trim_end(), ".#"))
+                .collect::<Result<Tile, ParseError>>()?;
+
+            Ok((parse::number(contents, id)?, tile))
+        })
+        .collect()
+}
+
+pub fn part1(tilemap: &HashMap<i32, Tile>) -> i64 {
+    let length = tilemap.iter().next().unwrap().1.len();
+    let width = tilemap.iter().next().unwrap().1[0].len();
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::load_input;
+
+    #[ignore]
+    #[test]
+    fn test_sample_p1() {
+        let input = parse_input(&load_input!("sample.txt").unwrap()).unwrap();
+        assert_eq!(part1(&input), 20899048083289);
+    }
+}
@@ -1,3 +1,9 @@
+use aoc_2020_day25::{parse_input, part1};
+use aoc_common::input;
+
 fn main() {
-    println!("Hello, world!");
+    let (card_key, door_key) = input!("input.txt", parse_input);
+
+    let res_p1 = part1(card_key, door_key);
+    println!("res p1: {}", res_p1);
 }
@@ -44,19 +44,28 @@ impl Board {
     /// let board = Board::from_string(s);
     /// ```
     pub fn from_string(s: &str) -> Board {
+        let rows: Vec<Vec<usize>> = s
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|marker| marker.parse::<usize>().unwrap())
+                    .collect()
+            })
+            .collect();
+
+        Board::from_rows(&rows)
+    }
+
+    /// Creates a board from rows of numbers that have already been parsed.
+    pub fn from_rows(rows: &[Vec<usize>]) -> Board {
         let mut board = Board::new();
 
-        // each line is a row
-        s.lines().enumerate().for_each(|(row, line)| {
-            // for each number in the col
-            line.split_whitespace()
-                .enumerate()
-                // insert into the board cells
-                .for_each(|(col, marker)| {
-                    let num = marker.parse::<usize>().unwrap();
-                    board.cells.insert(num, (row, col));
-                })
-        });
+        // insert each number into the board cells
+        for (row, nums) in rows.iter().enumerate() {
+            for (col, &num) in nums.iter().enumerate() {
+                board.cells.insert(num, (row, col));
+            }
+        }
 
         board
     }
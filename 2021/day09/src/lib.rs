@@ -0,0 +1,194 @@
+#![crate_name = "aoc_2021_day09"]
+
+//! ## --- Day 9: Smoke Basin ---
+//!
+//! These caves seem to be lava tubes. Parts are even still volcanically active; small hydrothermal vents release smoke into the caves that slowly settles like rain.
+//!
+//! If you can model how the smoke flows through the caves, you might be able to avoid it and be that much safer. The submarine generates a heightmap of the floor of the nearby caves for you (your puzzle input).
+//!
+//! Smoke flows to the lowest point of the area it's in. For example, consider the following heightmap:
+//!
+//! ```
+//! 2199943210
+//! 3987894921
+//! 9856789892
+//! 8767896789
+//! 9899965678
+//! ```
+//!
+//! Each number corresponds to the height of a particular location, where 9 is the highest and 0 is the lowest a location can be.
+//!
+//! Your first goal is to find the low points - the locations that are lower than any of its adjacent locations. Most locations have four adjacent locations (up, down, left, and right); locations on the edge or corner of the map have three or two adjacent locations, respectively. (Diagonal locations do not count as adjacent.)
+//!
+//! In the above example, there are four low points, all highlighted: two are in the first row (a 1 and a 0), one is in the third row (a 5), and one is in the bottom row (also a 5). All other locations on the heightmap have some lower adjacent location, and so are not low points.
+//!
+//! The risk level of a low point is 1 plus its height. In the above example, the risk levels of the low points are 2, 1, 6, and 6. The sum of the risk levels of all low points in the heightmap is therefore 15.
+//!
+//! Find all of the low points on your heightmap. What is the sum of the risk levels of all low points on your heightmap?
+//!
+//! Your puzzle answer was 502.
+//!
+//! ## --- Part Two ---
+//!
+//! Next, you need to find the largest basins so you know what areas are most important to avoid.
+//!
+//! A basin is all locations that eventually flow downward to a single low point. Therefore, every low point has a basin, although some basins are very small. Locations of height 9 do not count as being in any basin, and all other locations will always be part of exactly one basin.
+//!
+//! The size of a basin is the number of locations within the basin, including the low point. The example above has four basins.
+//!
+//! The top-left basin, size 3:
+//!
+//! ```
+//! 2199943210
+//! 3987894921
+//! 9856789892
+//! 8767896789
+//! 9899965678
+//! ```
+//!
+//! The top-right basin, size 9:
+//!
+//! ```
+//! 2199943210
+//! 3987894921
+//! 9856789892
+//! 8767896789
+//! 9899965678
+//! ```
+//!
+//! The middle basin, size 14:
+//!
+//! ```
+//! 2199943210
+//! 3987894921
+//! 9856789892
+//! 8767896789
+//! 9899965678
+//! ```
+//!
+//! The bottom-right basin, size 9:
+//!
+//! ```
+//! 2199943210
+//! 3987894921
+//! 9856789892
+//! 8767896789
+//! 9899965678
+//! ```
+//!
+//! Find the three largest basins and multiply their sizes together. In the above example, this is 9 _ 14 _ 9 = 1134.
+//!
+//! What do you get if you multiply together the sizes of the three largest basins?
+//!
+//! Your puzzle answer was 1330560.
+
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::{graph, parse, Answer, Example, ParseError, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Grid<usize>;
+
+    const EXAMPLES: &'static [Example] = &[Example::doc(0, 1, "15"), Example::doc(0, 2, "1134")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn part1(input: &Grid<usize>) -> usize {
+    find_minimums(input)
+        .iter()
+        .map(|&minimum| input[minimum] + 1)
+        .sum()
+}
+
+pub fn part2(input: &Grid<usize>) -> usize {
+    let mut basins: Vec<usize> = find_minimums(input)
+        .into_iter()
+        .map(|minimum| get_basin(input, minimum))
+        .collect();
+
+    basins.sort_unstable();
+    basins.reverse();
+
+    basins[0] * basins[1] * basins[2]
+}
+
+/// the size of the basin around `minimum`: every cell that can be reached
+/// from it without crossing a 9
+fn get_basin(input: &Grid<usize>, minimum: Pos) -> usize {
+    graph::reachable(minimum, |&pos| {
+        input
+            .neighbors4(pos)
+            .filter(|&neighbor| input[neighbor] != 9)
+    })
+    .len()
+}
+
+fn find_minimums(input: &Grid<usize>) -> Vec<Pos> {
+    // brute force, check each cell
+    input
+        .iter()
+        // 9 cannot be a minimum, it is the highest point
+        .filter(|&(_, &cell)| cell != 9)
+        .filter(|&(pos, &cell)| {
+            input
+                .neighbors4(pos)
+                .all(|neighbor| input[neighbor] >= cell)
+        })
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+pub fn parse_input(contents: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse_with(contents, parse::digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(
+            &[
+                "2199943210",
+                "3987894921",
+                "9856789892",
+                "8767896789",
+                "9899965678",
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+
+        assert_eq!(part1(&input), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(
+            &[
+                "2199943210",
+                "3987894921",
+                "9856789892",
+                "8767896789",
+                "9899965678",
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+
+        assert_eq!(part2(&input), 1134);
+    }
+}
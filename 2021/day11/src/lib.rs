@@ -0,0 +1,163 @@
+#![crate_name = "aoc_2021_day11"]
+
+use std::collections::HashSet;
+
+use aoc_common::grid::{Grid, Pos, ADJACENT};
+use aoc_common::{parse, Answer, Color, Example, Frame, Frames, ParseError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<usize>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::text(
+            "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526\n",
+            1,
+            "1656",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input, 100).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn animate(input: &Self::Input, part: u8) -> Option<Frames<'_>> {
+        match part {
+            1 => Some(Box::new(animate(input, 100))),
+            _ => None,
+        }
+    }
+}
+
+/// the octopuses that just flashed are bright, the ones about to flash are
+/// yellow and the rest fade out with their energy level
+const PALETTE: &[(char, Color)] = &[
+    ('0', Color::White),
+    ('1', Color::Gray),
+    ('2', Color::Gray),
+    ('3', Color::Gray),
+    ('4', Color::Blue),
+    ('5', Color::Blue),
+    ('6', Color::Blue),
+    ('7', Color::Cyan),
+    ('8', Color::Cyan),
+    ('9', Color::Yellow),
+];
+
+/// Simulates the octopuses for a number of steps and returns the total number
+/// of flashes.
+pub fn part1(input: &Grid<usize>, steps: usize) -> usize {
+    let mut grid = input.clone();
+
+    (0..steps).map(|_| step(&mut grid)).sum()
+}
+
+/// returns the octopuses after each of `steps` steps, starting with the
+/// initial energy levels
+pub fn animate(input: &Grid<usize>, steps: usize) -> impl Iterator<Item = Frame> {
+    let mut grid = input.clone();
+    let mut flashes = 0;
+
+    (0..=steps).map(move |step_num| {
+        if step_num > 0 {
+            flashes += step(&mut grid);
+        }
+
+        let caption = format!("step {}: {} flashes", step_num, flashes);
+        Frame::new(caption, grid.to_string()).with_palette(PALETTE)
+    })
+}
+
+pub fn parse_input(contents: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse_with(contents, parse::digits)
+}
+
+/// Runs a single step and returns the number of octopuses that flashed:
+///
+/// - the energy level of each octopus increases by 1.
+/// - any octopus with an energy level greater than 9 flashes, increasing the
+///   energy level of all adjacent octopuses by 1 (including diagonals). an
+///   octopus can only flash once per step.
+/// - any octopus that flashed has its energy level set to 0.
+fn step(grid: &mut Grid<usize>) -> usize {
+    let mut to_flash: Vec<Pos> = Vec::new();
+    let mut flashed: HashSet<Pos> = HashSet::new();
+
+    for (pos, energy) in grid.iter_mut() {
+        *energy += 1;
+
+        if *energy > 9 {
+            to_flash.push(pos);
+        }
+    }
+
+    while let Some(pos) = to_flash.pop() {
+        if !flashed.insert(pos) {
+            continue;
+        }
+
+        for dir in ADJACENT {
+            if let Some(neighbor) = grid.offset(pos, dir) {
+                grid[neighbor] += 1;
+
+                if grid[neighbor] > 9 && !flashed.contains(&neighbor) {
+                    to_flash.push(neighbor);
+                }
+            }
+        }
+    }
+
+    for &pos in &flashed {
+        grid[pos] = 0;
+    }
+
+    flashed.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(
+            &[
+                "5483143223",
+                "2745854711",
+                "5264556173",
+                "6141336146",
+                "6357385478",
+                "4167524645",
+                "2176841721",
+                "6882881134",
+                "4846848554",
+                "5283751526",
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+
+        assert_eq!(part1(&input, 100), 1656);
+    }
+
+    #[test]
+    fn test_animate() {
+        let input = parse_input("11111\n19991\n19191\n19991\n11111").unwrap();
+        let frames: Vec<Frame> = animate(&input, 2).collect();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].text, "11111\n19991\n19191\n19991\n11111");
+        assert_eq!(frames[1].text, "34543\n40004\n50005\n40004\n34543");
+        assert_eq!(frames[2].text, "45654\n51115\n61116\n51115\n45654");
+        assert_eq!(frames[2].caption, "step 2: 9 flashes");
+    }
+}
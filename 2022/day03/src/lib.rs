@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Example, ParseError, Solution};
+
+pub struct Day03;
+
+/// the rucksacks from the puzzle description
+const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::text(EXAMPLE, 1, "157"),
+        Example::text(EXAMPLE, 2, "70"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input)
+            .ok_or("a rucksack has no item in both compartments")
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input)
+            .ok_or("a group has no item in all three rucksacks")
+            .into()
+    }
+}
+
+/// parses the rucksacks, one per line. each one holds an even number of
+/// items (letters) and they come in groups of 3
+pub fn parse_input(contents: &str) -> Result<Vec<String>, ParseError> {
+    let rucksacks = contents
+        .lines()
+        .map(|line| {
+            if let Some(item) = line.chars().find(|c| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(
+                    contents,
+                    line,
+                    format!("expected only letters, found {:?}", item),
+                ));
+            }
+
+            if line.is_empty() || line.len() % 2 != 0 {
+                return Err(ParseError::at(
+                    contents,
+                    line,
+                    "expected an even number of items, half in each compartment",
+                ));
+            }
+
+            Ok(line.to_string())
+        })
+        .collect::<Result<Vec<String>, ParseError>>()?;
+
+    if rucksacks.len() % 3 != 0 {
+        return Err(ParseError::new(format!(
+            "expected groups of 3 rucksacks, found {} rucksacks",
+            rucksacks.len()
+        )));
+    }
+
+    Ok(rucksacks)
+}
+
+/// sums the priorities of the item in both compartments of each rucksack,
+/// `None` if a rucksack doesn't have one
+pub fn part1(rucksacks: &[String]) -> Option<usize> {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (s1, s2) = split_half(rucksack);
+            find_common(&[s1, s2]).map(calculate_priority)
+        })
+        .sum()
+}
+
+/// sums the priorities of the badge (the item in all three rucksacks) of
+/// each group, `None` if a group doesn't have one
+pub fn part2(rucksacks: &[String]) -> Option<usize> {
+    rucksacks
+        .chunks(3)
+        .map(|group| {
+            let group: Vec<&str> = group.iter().map(String::as_str).collect();
+            find_common(&group).map(calculate_priority)
+        })
+        .sum()
+}
+
+fn split_half(s: &str) -> (&str, &str) {
+    let l = s.len() / 2;
+    (&s[0..l], &s[l..])
+}
+
+/// returns an item that's in every one of `items`
+fn find_common(items: &[&str]) -> Option<char> {
+    let (first, rest) = items.split_first()?;
+    let mut common: HashSet<char> = first.chars().collect();
+
+    for s in rest {
+        let h: HashSet<char> = s.chars().collect();
+        common.retain(|c| h.contains(c));
+    }
+
+    common.into_iter().next()
+}
+
+/// a-z have priorities 1-26 and A-Z 27-52, items are always ascii letters
+fn calculate_priority(c: char) -> usize {
+    if c.is_ascii_lowercase() {
+        c as usize - 'a' as usize + 1
+    } else {
+        c as usize - 'A' as usize + 27
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let rucksacks = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&rucksacks), Some(157));
+        assert_eq!(part2(&rucksacks), Some(70));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_input("abc\nab\nab").is_err());
+        assert!(parse_input("a1\nab\nab").is_err());
+        assert!(parse_input("\nab\nab").is_err());
+        assert!(parse_input("ab\nab").is_err());
+    }
+
+    #[test]
+    fn test_nothing_in_common() {
+        let rucksacks = parse_input("ab\ncd\nef").unwrap();
+
+        assert_eq!(part1(&rucksacks), None);
+        assert_eq!(part2(&rucksacks), None);
+        assert_eq!(Day03::part1(&rucksacks), Answer::Unsolved);
+    }
+}
@@ -0,0 +1,118 @@
+//! Frames of a step simulation, which `aoc run --animate` plays in the
+//! terminal.
+
+use std::fmt::Write;
+
+/// the colors a [`Frame`] can use, rendered as ANSI escape codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    /// returns the ANSI SGR code of the color
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 93,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 97,
+            Color::Gray => 90,
+        }
+    }
+}
+
+/// a single generation of a simulation, rendered as text with one char per
+/// cell. the `palette` colors each char, anything not in it is uncolored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// shown above the frame, e.g. `"round 3"`
+    pub caption: String,
+    pub text: String,
+    palette: &'static [(char, Color)],
+}
+
+/// the frames of an animation, in order
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+impl Frame {
+    /// creates a new uncolored frame
+    pub fn new(caption: impl Into<String>, text: impl Into<String>) -> Frame {
+        Frame {
+            caption: caption.into(),
+            text: text.into(),
+            palette: &[],
+        }
+    }
+
+    /// colors every char of the frame that's in `palette`
+    pub fn with_palette(mut self, palette: &'static [(char, Color)]) -> Frame {
+        self.palette = palette;
+        self
+    }
+
+    /// returns the caption followed by the text, with ANSI colors if `color`
+    /// is set
+    pub fn render(&self, color: bool) -> String {
+        let mut out = format!("{}\n", self.caption);
+
+        if !color {
+            out.push_str(&self.text);
+            return out;
+        }
+
+        // only switch colors when the color changes, rather than on every char
+        let mut current = None;
+
+        for ch in self.text.chars() {
+            let next = self
+                .palette
+                .iter()
+                .find(|(c, _)| *c == ch)
+                .map(|(_, color)| *color);
+
+            if ch != '\n' && next != current {
+                match next {
+                    Some(color) => write!(out, "\x1b[{}m", color.code()).unwrap(),
+                    None => out.push_str("\x1b[0m"),
+                }
+                current = next;
+            }
+
+            out.push(ch);
+        }
+
+        if current.is_some() {
+            out.push_str("\x1b[0m");
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let frame = Frame::new("round 1", "##.\n.L#").with_palette(&[('#', Color::Red)]);
+
+        assert_eq!(frame.render(false), "round 1\n##.\n.L#");
+        assert_eq!(
+            frame.render(true),
+            "round 1\n\x1b[31m##\x1b[0m.\n.L\x1b[31m#\x1b[0m"
+        );
+        assert_eq!(Frame::new("", "ab").render(true), "\nab");
+    }
+}
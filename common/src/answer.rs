@@ -0,0 +1,234 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::Grid;
+
+/// the answer to a single part of a puzzle
+#[derive(Debug, Clone)]
+pub enum Answer {
+    /// most puzzles have an integer answer, which can be negative
+    Signed(i128),
+    /// an unsigned integer answer, for the counts and products that don't fit
+    /// in a signed one
+    Unsigned(u128),
+    /// some puzzles ask for a string instead (e.g. a password or the labels
+    /// of the cups)
+    Text(String),
+    /// some puzzles draw the answer with lit pixels instead, usually as block
+    /// letters
+    Pixels(Grid<bool>),
+    /// the part hasn't been solved yet, or the input has no answer
+    Unsolved,
+}
+
+/// how a pixel is drawn
+fn pixel(lit: &bool) -> char {
+    if *lit {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+impl Answer {
+    /// returns true if the part has been solved
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    /// returns true if the answer matches `expected`, an answer recorded in
+    /// an answers file or an example. numbers are compared by value, and
+    /// pixels are recorded as their rows separated by `/` (e.g. `#..#/####`)
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Signed(n) => expected.parse::<i128>().is_ok_and(|e| e == *n),
+            Answer::Unsigned(n) => expected.parse::<u128>().is_ok_and(|e| e == *n),
+            Answer::Text(s) => s == expected,
+            Answer::Pixels(grid) => grid.render(pixel).replace('\n', "/") == expected,
+            Answer::Unsolved => false,
+        }
+    }
+}
+
+/// numbers are equal when they have the same value, whether or not they're
+/// signed
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Unsigned(b)) | (Answer::Unsigned(b), Answer::Signed(a)) => {
+                u128::try_from(*a).is_ok_and(|a| a == *b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Pixels(a), Answer::Pixels(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Pixels(grid) => write!(f, "{}", grid.render(pixel)),
+            Answer::Unsolved => write!(f, "not implemented"),
+        }
+    }
+}
+
+/// numbers are serialized as JSON numbers when they fit in 64 bits and as
+/// strings otherwise, pixels as a list of rows and an unsolved part as `null`
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Signed(n) => match (i64::try_from(*n), u64::try_from(*n)) {
+                (Ok(n), _) => serializer.serialize_i64(n),
+                (_, Ok(n)) => serializer.serialize_u64(n),
+                _ => serializer.collect_str(n),
+            },
+            Answer::Unsigned(n) => match u64::try_from(*n) {
+                Ok(n) => serializer.serialize_u64(n),
+                Err(_) => serializer.collect_str(n),
+            },
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Pixels(grid) => serializer.collect_seq(
+                grid.iter_rows()
+                    .map(|row| row.iter().map(pixel).collect::<String>()),
+            ),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Signed, i128, i8, i16, i32, i64, i128, isize);
+impl_from_int!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(grid: Grid<bool>) -> Self {
+        Answer::Pixels(grid)
+    }
+}
+
+/// an input that parses but has no answer (wires that never cross) is
+/// unsolved, and why is logged instead of panicking
+impl<T: Into<Answer>, E: fmt::Display> From<Result<T, E>> for Answer {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(answer) => answer.into(),
+            Err(err) => {
+                log::warn!("{}", err);
+                Answer::Unsolved
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixels() -> Answer {
+        Answer::from(Grid::from_rows(vec![
+            vec![true, false, true],
+            vec![true, true, true],
+        ]))
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(42usize), Answer::Unsigned(42));
+        assert_eq!(Answer::from(-7i32), Answer::Signed(-7));
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+        assert_eq!(Answer::from(Ok::<_, String>(3u8)), Answer::Unsigned(3));
+        assert_eq!(Answer::from(Err::<u8, _>("no answer")), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Answer::Signed(42), Answer::Unsigned(42));
+        assert_eq!(Answer::Unsigned(42), Answer::Signed(42));
+        assert_ne!(Answer::Signed(-1), Answer::Unsigned(u128::MAX));
+        assert_ne!(Answer::Signed(42), Answer::from("42"));
+        assert_ne!(Answer::Unsolved, Answer::Signed(0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Signed(1727094849536).to_string(), "1727094849536");
+        assert_eq!(Answer::Signed(-7).to_string(), "-7");
+        assert_eq!(
+            Answer::Unsigned(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(Answer::Text("abc".to_string()).to_string(), "abc");
+        assert_eq!(pixels().to_string(), "#.#\n###");
+        assert_eq!(Answer::Unsolved.to_string(), "not implemented");
+        assert!(!Answer::Unsolved.is_solved());
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::Signed(-7).matches("-7"));
+        assert!(Answer::Unsigned(42).matches("42"));
+        assert!(!Answer::Unsigned(42).matches("-42"));
+        assert!(!Answer::Signed(42).matches("abc"));
+        assert!(Answer::from("abc").matches("abc"));
+        assert!(pixels().matches("#.#/###"));
+        assert!(!pixels().matches("#.#"));
+        assert!(!Answer::Unsolved.matches("not implemented"));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+
+        assert_eq!(json(Answer::Signed(-42)), "-42");
+        assert_eq!(
+            json(Answer::Signed(u64::MAX as i128)),
+            "18446744073709551615"
+        );
+        assert_eq!(
+            json(Answer::Signed(u64::MAX as i128 + 1)),
+            r#""18446744073709551616""#
+        );
+        assert_eq!(
+            json(Answer::Unsigned(u128::MAX)),
+            r#""340282366920938463463374607431768211455""#
+        );
+        assert_eq!(json(Answer::from("abc")), r#""abc""#);
+        assert_eq!(json(pixels()), "[\"#.#\",\"###\"]");
+        assert_eq!(json(Answer::Unsolved), "null");
+    }
+}
@@ -0,0 +1,143 @@
+//! Worked examples from the puzzle descriptions, which `aoc examples` runs
+//! each day's solution against.
+
+use std::fs;
+use std::path::Path;
+
+/// where the input of an [`Example`] comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExampleInput {
+    /// the nth (0-based) code block of the day's crate docs
+    Doc(usize),
+    /// the input itself
+    Text(&'static str),
+    /// a file in the day's crate directory
+    File(&'static str),
+}
+
+/// an example input for one part of a puzzle, and its expected answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: ExampleInput,
+    pub part: u8,
+    /// the expected answer, as it's displayed
+    pub answer: &'static str,
+}
+
+impl Example {
+    /// an example whose input is the `block`th (0-based) code block of the
+    /// day's crate docs
+    pub const fn doc(block: usize, part: u8, answer: &'static str) -> Self {
+        Example {
+            input: ExampleInput::Doc(block),
+            part,
+            answer,
+        }
+    }
+
+    /// an example with an inline input
+    pub const fn text(input: &'static str, part: u8, answer: &'static str) -> Self {
+        Example {
+            input: ExampleInput::Text(input),
+            part,
+            answer,
+        }
+    }
+
+    /// an example whose input is a file in the day's crate directory
+    pub const fn file(name: &'static str, part: u8, answer: &'static str) -> Self {
+        Example {
+            input: ExampleInput::File(name),
+            part,
+            answer,
+        }
+    }
+
+    /// returns the example's input. `crate_dir` is the day's crate directory,
+    /// the docs are read from its `src/lib.rs` (or `src/main.rs`)
+    pub fn load(&self, crate_dir: &Path) -> Result<String, String> {
+        match self.input {
+            ExampleInput::Text(text) => Ok(text.to_string()),
+            ExampleInput::File(name) => {
+                let path = crate_dir.join(name);
+                fs::read_to_string(&path)
+                    .map_err(|err| format!("couldn't read {}: {}", path.display(), err))
+            }
+            ExampleInput::Doc(block) => {
+                let source = ["lib.rs", "main.rs"]
+                    .iter()
+                    .find_map(|file| fs::read_to_string(crate_dir.join("src").join(file)).ok())
+                    .ok_or_else(|| format!("no source in {}", crate_dir.display()))?;
+
+                doc_blocks(&source)
+                    .into_iter()
+                    .nth(block)
+                    .ok_or_else(|| format!("the docs don't have a code block #{}", block))
+            }
+        }
+    }
+}
+
+/// returns the contents of every fenced code block in the `//!` docs of
+/// `source`, in order
+pub fn doc_blocks(source: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut block: Option<Vec<&str>> = None;
+
+    let docs = source.lines().filter_map(|line| {
+        let line = line.trim_start().strip_prefix("//!")?;
+        Some(line.strip_prefix(' ').unwrap_or(line))
+    });
+
+    for line in docs {
+        match (&mut block, line.trim_start().starts_with("```")) {
+            (None, true) => block = Some(Vec::new()),
+            (Some(lines), true) => {
+                blocks.push(lines.join("\n") + "\n");
+                block = None;
+            }
+            (Some(lines), false) => lines.push(line),
+            (None, false) => {}
+        }
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doc_blocks() {
+        let source = "//! # Day 1\n//!\n//! ```\n//! 1\n//!  2\n//! ```\n//!\n//! ```\n//! a\n//! ```\n\n/// ```\n/// not docs\n/// ```\nfn main() {}\n";
+
+        assert_eq!(doc_blocks(source), vec!["1\n 2\n", "a\n"]);
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-example-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(
+            dir.join("src/lib.rs"),
+            "//! ```\n//! 1721\n//! 979\n//! ```\n",
+        )
+        .unwrap();
+        fs::write(dir.join("sample.txt"), "1\n2\n").unwrap();
+
+        assert_eq!(
+            Example::doc(0, 1, "").load(&dir),
+            Ok("1721\n979\n".to_string())
+        );
+        assert_eq!(
+            Example::file("sample.txt", 1, "").load(&dir),
+            Ok("1\n2\n".to_string())
+        );
+        assert_eq!(Example::text("3", 1, "").load(&dir), Ok("3".to_string()));
+        assert!(Example::doc(1, 1, "").load(&dir).is_err());
+        assert!(Example::file("missing.txt", 1, "").load(&dir).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
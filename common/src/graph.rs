@@ -0,0 +1,664 @@
+//! Searches over graphs whose neighbors are worked out as the search goes,
+//! and a [`Graph`] for the ones whose edges are all listed in the input.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// the number of steps from `start` to every node reachable from it, where
+/// `neighbors` returns the nodes one step away from a node
+pub fn bfs<N, F, I>(start: N, mut neighbors: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, step)) = queue.pop_front() {
+        for next in neighbors(&node) {
+            if !steps.contains_key(&next) {
+                steps.insert(next.clone(), step + 1);
+                queue.push_back((next, step + 1));
+            }
+        }
+    }
+
+    steps
+}
+
+/// every node reachable from `start`, in the order a depth first search
+/// visits them (a node's neighbors are visited in the order `neighbors`
+/// returns them)
+pub fn dfs<N, F, I>(start: N, mut neighbors: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+
+        let next: Vec<N> = neighbors(&node).into_iter().collect();
+        stack.extend(next.into_iter().rev().filter(|next| !seen.contains(next)));
+        order.push(node);
+    }
+
+    order
+}
+
+/// every node reachable from `start`, including `start` itself
+pub fn reachable<N, F, I>(start: N, neighbors: F) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    bfs(start, neighbors).into_keys().collect()
+}
+
+/// the lowest cost from `start` to every node reachable from it, where
+/// `neighbors` returns the nodes one step away from a node with the cost of
+/// that step. costs can't be negative
+pub fn dijkstra<N, C, F, I>(start: N, neighbors: F) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let search = Search::run(start, neighbors, |_| C::default(), |_| false);
+
+    search
+        .nodes
+        .into_iter()
+        .zip(search.best)
+        .map(|(node, (cost, _))| (node, cost))
+        .collect()
+}
+
+/// the lowest cost from `start` to the first node `is_goal` accepts, and
+/// the path there (including both ends), or `None` if no goal is reachable
+pub fn shortest_path<N, C, F, I, G>(start: N, neighbors: F, is_goal: G) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// like [`shortest_path`], guided by `heuristic`, an estimate of the cost
+/// from a node to the closest goal. the path is only the shortest one if the
+/// estimate never overestimates it (e.g. the manhattan distance on a grid)
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    neighbors: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let search = Search::run(start, neighbors, heuristic, is_goal);
+    let goal = search.goal?;
+
+    let mut path = vec![search.nodes[goal].clone()];
+    let mut node = goal;
+    while let Some(parent) = search.best[node].1 {
+        path.push(search.nodes[parent].clone());
+        node = parent;
+    }
+    path.reverse();
+
+    Some((search.best[goal].0, path))
+}
+
+/// the state of a weighted search. nodes are numbered in the order they're
+/// found so the heap doesn't need them to be ordered
+struct Search<N, C> {
+    nodes: Vec<N>,
+    /// the lowest cost found to each node, and the node it was reached from
+    best: Vec<(C, Option<usize>)>,
+    /// the goal the search stopped at
+    goal: Option<usize>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+{
+    fn run<F, I, H, G>(start: N, mut neighbors: F, mut heuristic: H, mut is_goal: G) -> Self
+    where
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, C)>,
+        H: FnMut(&N) -> C,
+        G: FnMut(&N) -> bool,
+    {
+        let mut ids = HashMap::from([(start.clone(), 0)]);
+        let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+        let mut search = Search {
+            nodes: vec![start],
+            best: vec![(C::default(), None)],
+            goal: None,
+        };
+
+        while let Some(Reverse((_, cost, id))) = heap.pop() {
+            // a cheaper way there was found after this one was queued
+            if cost > search.best[id].0 {
+                continue;
+            }
+
+            let node = search.nodes[id].clone();
+            if is_goal(&node) {
+                search.goal = Some(id);
+                break;
+            }
+
+            for (next, step) in neighbors(&node) {
+                let next_cost = cost + step;
+                let next_id = match ids.get(&next) {
+                    Some(&next_id) if next_cost >= search.best[next_id].0 => continue,
+                    Some(&next_id) => {
+                        search.best[next_id] = (next_cost, Some(id));
+                        next_id
+                    }
+                    None => {
+                        ids.insert(next.clone(), search.nodes.len());
+                        search.nodes.push(next);
+                        search.best.push((next_cost, Some(id)));
+                        search.nodes.len() - 1
+                    }
+                };
+
+                let estimate = next_cost + heuristic(&search.nodes[next_id]);
+                heap.push(Reverse((estimate, next_cost, next_id)));
+            }
+        }
+
+        search
+    }
+}
+
+/// a directed graph with a weight on each edge (use `()` when there isn't
+/// one), stored as the list of edges leaving each node
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = ()> {
+    nodes: Vec<N>,
+    ids: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<N, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, W: Clone> Graph<N, W> {
+    /// creates an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds `node` if it isn't in the graph yet, returns its index
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+
+        self.ids.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    /// adds an edge from `from` to `to`, adding the nodes if needed
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, weight));
+    }
+
+    /// the number of nodes in the graph
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// returns true if the graph has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// returns true if `node` is in the graph
+    pub fn contains(&self, node: &N) -> bool {
+        self.ids.contains_key(node)
+    }
+
+    /// every node, in the order they were added
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// the edges leaving `node` and their weights, in the order they were
+    /// added. a node that isn't in the graph has none
+    pub fn edges(&self, node: &N) -> impl Iterator<Item = (&N, &W)> {
+        self.ids
+            .get(node)
+            .map_or(&[][..], |&id| &self.edges[id])
+            .iter()
+            .map(|(to, weight)| (&self.nodes[*to], weight))
+    }
+
+    /// the nodes an edge from `node` goes to
+    pub fn neighbors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.edges(node).map(|(to, _)| to)
+    }
+
+    /// the same graph with every edge going the other way
+    pub fn reversed(&self) -> Self {
+        let mut edges = vec![Vec::new(); self.nodes.len()];
+        for (from, out) in self.edges.iter().enumerate() {
+            for (to, weight) in out {
+                edges[*to].push((from, weight.clone()));
+            }
+        }
+
+        Graph {
+            nodes: self.nodes.clone(),
+            ids: self.ids.clone(),
+            edges,
+        }
+    }
+
+    /// the graph with only the nodes `keep` accepts, and the edges between
+    /// them. the nodes stay in the order they were added
+    pub fn subgraph(&self, mut keep: impl FnMut(&N) -> bool) -> Self {
+        let kept: Vec<bool> = self.nodes.iter().map(&mut keep).collect();
+        let mut graph = Graph::new();
+
+        // every node goes in before any edge, which would add its target early
+        for (node, _) in self.nodes.iter().zip(&kept).filter(|(_, &kept)| kept) {
+            graph.add_node(node.clone());
+        }
+
+        for (from, node) in self.nodes.iter().enumerate().filter(|&(id, _)| kept[id]) {
+            for (to, weight) in self.edges[from].iter().filter(|(to, _)| kept[*to]) {
+                graph.add_edge(node.clone(), self.nodes[*to].clone(), weight.clone());
+            }
+        }
+
+        graph
+    }
+
+    /// every node reachable from `start`, including `start` itself
+    pub fn reachable(&self, start: &N) -> HashSet<N> {
+        match self.ids.get(start) {
+            Some(&id) => reachable(id, |&node| self.edges[node].iter().map(|(to, _)| *to))
+                .into_iter()
+                .map(|id| self.nodes[id].clone())
+                .collect(),
+            None => HashSet::from([start.clone()]),
+        }
+    }
+
+    /// the number of nodes reachable from `start`, not counting `start`
+    /// itself (unless it's on a cycle)
+    pub fn count_reachable(&self, start: &N) -> usize {
+        let id = match self.ids.get(start) {
+            Some(&id) => id,
+            None => return 0,
+        };
+
+        // the start only counts when it can be reached again, so the search
+        // starts from the nodes after it
+        let mut seen = HashSet::new();
+        let mut stack: Vec<usize> = self.edges[id].iter().map(|(to, _)| *to).collect();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node) {
+                stack.extend(self.edges[node].iter().map(|(to, _)| *to));
+            }
+        }
+
+        seen.len()
+    }
+
+    /// the nodes ordered so that every edge goes from an earlier node to a
+    /// later one, or `None` if the graph has a cycle. whenever several nodes
+    /// could come next, the one that was added first does
+    pub fn toposort(&self) -> Option<Vec<N>> {
+        let mut incoming = vec![0; self.nodes.len()];
+        for (to, _) in self.edges.iter().flatten() {
+            incoming[*to] += 1;
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.nodes.len())
+            .filter(|&id| incoming[id] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(Reverse(id)) = ready.pop() {
+            order.push(self.nodes[id].clone());
+            for (to, _) in &self.edges[id] {
+                incoming[*to] -= 1;
+                if incoming[*to] == 0 {
+                    ready.push(Reverse(*to));
+                }
+            }
+        }
+
+        (order.len() == self.nodes.len()).then_some(order)
+    }
+
+    /// splits the graph into strongly connected components, the groups of
+    /// nodes that can all reach each other. a component comes before every
+    /// component it has an edge to (so the sinks come last)
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        // tarjan's algorithm, with the recursion kept on a stack of (node,
+        // index of the next edge to follow)
+        const UNVISITED: usize = usize::MAX;
+
+        let mut index = vec![UNVISITED; self.nodes.len()];
+        let mut low = vec![0; self.nodes.len()];
+        let mut on_stack = vec![false; self.nodes.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..self.nodes.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+
+            let mut calls = vec![(root, 0)];
+            index[root] = next_index;
+            low[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(call) = calls.last_mut() {
+                let (node, edge) = *call;
+
+                if let Some(&(next, _)) = self.edges[node].get(edge) {
+                    call.1 += 1;
+
+                    if index[next] == UNVISITED {
+                        index[next] = next_index;
+                        low[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        calls.push((next, 0));
+                    } else if on_stack[next] {
+                        low[node] = low[node].min(index[next]);
+                    }
+                    continue;
+                }
+
+                // every edge has been followed, return to the caller
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+
+                if low[node] == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(self.nodes[member].clone());
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        // tarjan finds the sinks first
+        components.reverse();
+        components
+    }
+}
+
+impl<N: Clone + Eq + Hash, W: Clone> FromIterator<(N, N, W)> for Graph<N, W> {
+    fn from_iter<T: IntoIterator<Item = (N, N, W)>>(edges: T) -> Self {
+        let mut graph = Graph::new();
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    fn maze() -> Grid<char> {
+        Grid::parse("..#.\n.#..\n....\n#.#.", ".#").unwrap()
+    }
+
+    /// the open cells next to `pos`
+    fn open(grid: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbors4(pos).filter(|&n| grid[n] == '.').collect()
+    }
+
+    fn sorted<T: Ord>(mut items: Vec<T>) -> Vec<T> {
+        items.sort();
+        items
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let steps = bfs((0, 0), |&pos| open(&grid, pos));
+
+        assert_eq!(steps[&(0, 0)], 0);
+        assert_eq!(steps[&(2, 2)], 4);
+        assert_eq!(steps[&(0, 3)], 7);
+        assert_eq!(steps.len(), 12);
+        assert!(!steps.contains_key(&(3, 0)));
+    }
+
+    #[test]
+    fn test_dfs() {
+        let edges = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4]), (4, vec![1])]);
+        let order = dfs(1, |n| edges[n].clone());
+
+        assert_eq!(order, [1, 2, 4, 3]);
+        assert_eq!(
+            sorted(reachable(3, |n| edges[n].clone()).into_iter().collect()),
+            [1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('b', vec![('d', 1)]),
+            ('c', vec![('b', 3), ('d', 8)]),
+            ('d', vec![]),
+            ('e', vec![('a', 1)]),
+        ]);
+        let costs = dijkstra('a', |n| edges[n].clone());
+
+        assert_eq!(
+            costs,
+            HashMap::from([('a', 0), ('b', 5), ('c', 2), ('d', 6)])
+        );
+        assert_eq!(
+            shortest_path('a', |n| edges[n].clone(), |&n| n == 'd'),
+            Some((6, vec!['a', 'c', 'b', 'd']))
+        );
+        assert_eq!(
+            shortest_path('a', |n| edges[n].clone(), |&n| n == 'e'),
+            None
+        );
+        assert_eq!(
+            shortest_path('a', |n| edges[n].clone(), |&n| n == 'a'),
+            Some((0, vec!['a']))
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = maze();
+        let goal = (0, 3);
+        let distance = |&(r, c): &(usize, usize)| r.abs_diff(goal.0) + c.abs_diff(goal.1);
+        let neighbors = |&pos: &(usize, usize)| open(&grid, pos).into_iter().map(|n| (n, 1));
+
+        let (cost, path) = astar((0, 0), neighbors, distance, |&pos| pos == goal).unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[7]), ((0, 0), goal));
+        assert!(path
+            .windows(2)
+            .all(|step| distance(&step[0]).abs_diff(distance(&step[1])) == 1));
+
+        assert_eq!(
+            astar((0, 0), neighbors, distance, |&pos| pos == (3, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_graph() {
+        let graph: Graph<&str, u32> = [("a", "b", 1), ("a", "c", 2), ("c", "b", 3)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(graph.len(), 3);
+        assert!(graph.contains(&"c") && !graph.contains(&"d"));
+        assert_eq!(
+            graph.edges(&"a").collect::<Vec<_>>(),
+            [(&"b", &1), (&"c", &2)]
+        );
+        assert_eq!(graph.neighbors(&"d").count(), 0);
+
+        let reversed = graph.reversed();
+        assert_eq!(
+            reversed.edges(&"b").collect::<Vec<_>>(),
+            [(&"a", &1), (&"c", &3)]
+        );
+        assert_eq!(reversed.neighbors(&"a").count(), 0);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let graph: Graph<u32> = [(1, 2, ()), (2, 3, ()), (3, 2, ()), (4, 1, ()), (5, 5, ())]
+            .into_iter()
+            .collect();
+
+        assert_eq!(sorted(graph.reachable(&2).into_iter().collect()), [2, 3]);
+        assert_eq!(graph.count_reachable(&1), 2);
+        assert_eq!(graph.count_reachable(&2), 2);
+        assert_eq!(graph.count_reachable(&3), 2);
+        assert_eq!(graph.count_reachable(&5), 1);
+        assert_eq!(graph.reversed().count_reachable(&3), 4);
+        assert_eq!(graph.count_reachable(&9), 0);
+    }
+
+    #[test]
+    fn test_toposort() {
+        let mut graph: Graph<char> = [
+            ('b', 'd', ()),
+            ('a', 'b', ()),
+            ('a', 'c', ()),
+            ('c', 'd', ()),
+        ]
+        .into_iter()
+        .collect();
+        graph.add_node('e');
+
+        // a and e are ready first, then b and c once a is done
+        assert_eq!(graph.toposort(), Some(vec!['a', 'b', 'c', 'd', 'e']));
+
+        graph.add_edge('d', 'a', ());
+        assert_eq!(graph.toposort(), None);
+
+        // the loop goes through d, so leaving it out breaks it
+        let without_d = graph.subgraph(|&node| node != 'd');
+        assert_eq!(without_d.toposort(), Some(vec!['a', 'b', 'c', 'e']));
+        assert_eq!(without_d.edges(&'b').count(), 0);
+    }
+
+    #[test]
+    fn test_subgraph_order() {
+        let mut graph: Graph<char> = Graph::new();
+        graph.add_node('x');
+        graph.add_node('y');
+        graph.add_node('z');
+        graph.add_edge('x', 'z', ());
+
+        // keeping every node keeps them in the order they were added, even
+        // though the edge to z comes before y
+        let all = graph.subgraph(|_| true);
+        assert_eq!(all.nodes().copied().collect::<Vec<_>>(), ['x', 'y', 'z']);
+        assert_eq!(all.toposort(), Some(vec!['x', 'y', 'z']));
+
+        let without_y = graph.subgraph(|&node| node != 'y');
+        assert_eq!(without_y.nodes().copied().collect::<Vec<_>>(), ['x', 'z']);
+        assert_eq!(without_y.neighbors(&'x').collect::<Vec<_>>(), [&'z']);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph: Graph<u32> = [
+            (1, 2, ()),
+            (2, 3, ()),
+            (3, 1, ()),
+            (3, 4, ()),
+            (4, 5, ()),
+            (5, 4, ()),
+            (6, 5, ()),
+            (7, 7, ()),
+        ]
+        .into_iter()
+        .collect();
+
+        let components: Vec<Vec<u32>> = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(sorted)
+            .collect();
+
+        assert_eq!(components.len(), 4);
+        assert!(components.contains(&vec![1, 2, 3]));
+        assert!(components.contains(&vec![6]));
+        assert!(components.contains(&vec![7]));
+
+        // every edge goes to the same component or a later one
+        let component = |n: &u32| components.iter().position(|c| c.contains(n)).unwrap();
+        for node in graph.nodes() {
+            assert!(graph
+                .neighbors(node)
+                .all(|next| component(next) >= component(node)));
+        }
+        assert_eq!(components[component(&4)], [4, 5]);
+    }
+}
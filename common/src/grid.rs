@@ -0,0 +1,385 @@
+//! A dense, rectangular 2D grid for the puzzles that come as a character
+//! map. Positions are `(row, col)` with `(0, 0)` in the top left corner.
+//!
+//! ```
+//! use aoc_common::Grid;
+//!
+//! let forest = Grid::parse("..#\n#..", ".#").unwrap().wrap_horizontally();
+//! assert_eq!((forest.rows(), forest.cols()), (2, 3));
+//! assert_eq!(forest[(0, 2)], '#');
+//!
+//! // walking off the right edge of a wrapping grid comes back on the left
+//! assert_eq!(forest.offset((1, 2), (0, 1)), Some((1, 0)));
+//! assert_eq!(forest.neighbors4((0, 0)).count(), 3);
+//! ```
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// a `(row, col)` position in a grid
+pub type Pos = (usize, usize);
+
+/// a `(row, col)` offset to move by
+pub type Dir = (isize, isize);
+
+/// the 4 orthogonal directions, clockwise starting from up
+pub const ORTHOGONAL: [Dir; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// all 8 directions (including diagonals), clockwise starting from up
+pub const ADJACENT: [Dir; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+    wrap: bool,
+}
+
+impl<T> Grid<T> {
+    /// creates a `rows` x `cols` grid with every cell set to `value`
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+            wrap: false,
+        }
+    }
+
+    /// creates a grid from its rows. panics if the rows aren't all the same
+    /// length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "every row of a grid must be the same length"
+        );
+
+        Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+            wrap: false,
+        }
+    }
+
+    /// parses a grid with `parse_row`, which is given the whole input and the
+    /// line to parse (e.g. [`parse::digits`](crate::parse::digits)). blank
+    /// lines are skipped and every row must be the same length
+    pub fn parse_with<F>(input: &str, mut parse_row: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(&str, &str) -> Result<Vec<T>, ParseError>,
+    {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let row = parse_row(input, line)?;
+
+            if rows == 0 {
+                cols = row.len();
+            } else if row.len() != cols {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected {} columns, found {}", cols, row.len()),
+                ));
+            }
+
+            cells.extend(row);
+            rows += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            rows,
+            cols,
+            wrap: false,
+        })
+    }
+
+    /// makes the grid wrap around horizontally: moving off of one side of a
+    /// row continues on the other side of the same row
+    pub fn wrap_horizontally(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// the number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// the number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// returns the cell at `pos`, or `None` if it's out of bounds. the column
+    /// wraps around if the grid wraps horizontally
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    /// returns a mutable reference to the cell at `pos`, see [`Grid::get`]
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(move |i| &mut self.cells[i])
+    }
+
+    /// moves from `pos` by `dir` and returns the new position, or `None` if
+    /// it falls off of the grid
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): Dir) -> Option<Pos> {
+        let row = row
+            .checked_add_signed(d_row)
+            .filter(|&row| row < self.rows)?;
+
+        let col = if self.wrap && self.cols > 0 {
+            (col as isize + d_col).rem_euclid(self.cols as isize) as usize
+        } else {
+            col.checked_add_signed(d_col)
+                .filter(|&col| col < self.cols)?
+        };
+
+        Some((row, col))
+    }
+
+    /// returns the positions that are one step away from `pos` in each of
+    /// `dirs` and still on the grid
+    pub fn neighbors<'a>(&'a self, pos: Pos, dirs: &'a [Dir]) -> impl Iterator<Item = Pos> + 'a {
+        dirs.iter().filter_map(move |&dir| self.offset(pos, dir))
+    }
+
+    /// returns the up to 4 orthogonal neighbors of `pos`
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &ORTHOGONAL)
+    }
+
+    /// returns the up to 8 neighbors of `pos`, including diagonals
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &ADJACENT)
+    }
+
+    /// returns every position from `start` (exclusive) in the direction
+    /// `dir` until it falls off of the grid. when the grid wraps
+    /// horizontally, the ray stops before it comes back around to `start`
+    pub fn ray(&self, start: Pos, dir: Dir) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(start, dir), move |&pos| self.offset(pos, dir))
+            .take_while(move |&pos| pos != start)
+    }
+
+    /// returns the cells of row `row`
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// returns the cells of column `col`, from top to bottom
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {} is out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// returns every row, from top to bottom
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't accept 0, a grid without columns has no cells
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    /// returns every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// returns every position and its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// returns every position and a mutable reference to its cell, row by row
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    /// creates a new grid of the same size by calling `f` on every position
+    /// and its cell
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Pos, &T) -> U,
+    {
+        Grid {
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+            rows: self.rows,
+            cols: self.cols,
+            wrap: self.wrap,
+        }
+    }
+
+    /// renders the grid back into text, one line per row, using `f` to turn
+    /// each cell into a character
+    pub fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        self.iter_rows()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn index_of(&self, (row, col): Pos) -> Option<usize> {
+        let col = if self.wrap && self.cols > 0 {
+            col % self.cols
+        } else {
+            col
+        };
+
+        (row < self.rows && col < self.cols).then(|| row * self.cols + col)
+    }
+}
+
+impl Grid<char> {
+    /// parses a character map, erroring on any character that isn't in
+    /// `allowed`
+    pub fn parse(input: &str, allowed: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, |input, line| crate::parse::row(input, line, allowed))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.index_of(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "position {:?} is out of bounds for a {}x{} grid",
+                pos, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.index_of(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "position {:?} is out of bounds for a {}x{} grid",
+                pos, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#..\r\n.#.\n", ".#").unwrap();
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.to_string(), "#..\n.#.");
+
+        let err = Grid::parse("#..\n.x.\n", ".#").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+        let err = Grid::parse_with("123\n45\n", parse::digits).unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "expected 3 columns, found 2")
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<Pos>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+
+        // wrapping around adds the neighbors on the other side of the row
+        let grid = grid.wrap_horizontally();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<Pos>>(),
+            vec![(0, 1), (1, 0), (0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::new(3, 4, 0);
+
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<Pos>>(),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.ray((0, 3), (0, 1)).count(), 0);
+
+        let grid = grid.wrap_horizontally();
+        assert_eq!(
+            grid.ray((0, 0), (1, 3)).collect::<Vec<Pos>>(),
+            vec![(1, 3), (2, 2)]
+        );
+        // a horizontal ray stops after one lap
+        assert_eq!(
+            grid.ray((1, 1), (0, 1)).collect::<Vec<Pos>>(),
+            vec![(1, 2), (1, 3), (1, 0)]
+        );
+        assert_eq!(grid[(0, 5)], grid[(0, 1)]);
+    }
+
+    #[test]
+    fn test_views() {
+        let mut grid = Grid::parse_with("123\n456\n", parse::digits).unwrap();
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<usize>>(), vec![3, 6]);
+        assert_eq!(grid.iter_rows().count(), 2);
+        assert_eq!(grid.get((2, 0)), None);
+
+        grid[(0, 0)] = 9;
+        let doubled = grid.map(|_, &n| n * 2);
+        assert_eq!(doubled.row(0), &[18, 4, 6]);
+        assert_eq!(grid.render(|&n| if n > 4 { '#' } else { '.' }), "#..\n.##");
+    }
+}
@@ -0,0 +1,257 @@
+//! Loads puzzle inputs without depending on the current working directory.
+//!
+//! Inputs are looked up in the following order:
+//!
+//! 1. an explicit path (e.g. from `--input PATH`)
+//! 2. stdin, when the explicit path is `-`
+//! 3. `$AOC_INPUT_DIR/<year>/<day>/<name>`, when `AOC_INPUT_DIR` is set and the
+//!    file exists
+//! 4. `<name>` in the day's own crate directory (`CARGO_MANIFEST_DIR`)
+
+use std::env;
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// the environment variable pointing to a directory laid out like this
+/// repository (`<year>/<day>/input.txt`) that overrides the committed inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// the explicit path that reads the input from stdin
+pub const STDIN: &str = "-";
+
+/// an error loading a puzzle input, names every path that was tried
+#[derive(Debug)]
+pub enum InputError {
+    /// the input exists but couldn't be read
+    Read { path: String, source: io::Error },
+    /// none of the candidate paths exist
+    NotFound { tried: Vec<PathBuf> },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Read { path, source } => {
+                write!(f, "failed to read input from {}: {}", path, source)
+            }
+            InputError::NotFound { tried } => {
+                let tried: Vec<String> = tried.iter().map(|p| p.display().to_string()).collect();
+                write!(f, "input not found, tried {}", tried.join(" and "))
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Read { source, .. } => Some(source),
+            InputError::NotFound { .. } => None,
+        }
+    }
+}
+
+/// returns the paths where `name` is looked up for the crate in `crate_dir`
+/// when no explicit path is given, in order
+fn candidates(input_dir: Option<&OsStr>, crate_dir: &Path, name: &str) -> Vec<PathBuf> {
+    let mut paths = vec![];
+
+    if let Some(dir) = input_dir.filter(|dir| !dir.is_empty()) {
+        // the last two components of a day's crate dir are <year>/<day>
+        let components: Vec<&OsStr> = crate_dir.iter().collect();
+        let day_dir: PathBuf = components[components.len().saturating_sub(2)..]
+            .iter()
+            .collect();
+
+        paths.push(Path::new(dir).join(day_dir).join(name));
+    }
+
+    paths.push(crate_dir.join(name));
+    paths
+}
+
+fn read_path(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    let mut contents = String::new();
+
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(|source| InputError::Read {
+            path: "stdin".to_string(),
+            source,
+        })?;
+
+    Ok(contents)
+}
+
+/// returns the path the input `name` for the crate in `crate_dir` is read
+/// from when no explicit path is given, or `None` if it doesn't exist
+pub fn find(crate_dir: &Path, name: &str) -> Option<PathBuf> {
+    candidates(env::var_os(INPUT_DIR_VAR).as_deref(), crate_dir, name)
+        .into_iter()
+        .find(|path| path.exists())
+}
+
+/// loads the input `name` for the crate in `crate_dir`, see the module docs
+/// for the lookup order
+pub fn load(explicit: Option<&str>, crate_dir: &Path, name: &str) -> Result<String, InputError> {
+    load_with_path(explicit, crate_dir, name).map(|(_, contents)| contents)
+}
+
+/// same as [`load`] but also returns the path the input was read from, to
+/// point parse errors at the right file
+pub fn load_with_path(
+    explicit: Option<&str>,
+    crate_dir: &Path,
+    name: &str,
+) -> Result<(String, String), InputError> {
+    match explicit {
+        Some(STDIN) => Ok(("stdin".to_string(), read_stdin()?)),
+        Some(path) => Ok((path.to_string(), read_path(Path::new(path))?)),
+        None => match find(crate_dir, name) {
+            Some(path) => Ok((path.display().to_string(), read_path(&path)?)),
+            None => Err(InputError::NotFound {
+                tried: candidates(env::var_os(INPUT_DIR_VAR).as_deref(), crate_dir, name),
+            }),
+        },
+    }
+}
+
+/// returns the value of `--input PATH` (or `--input=PATH`) from the command
+/// line arguments, if given
+pub fn input_arg() -> Option<String> {
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        if arg == "--input" {
+            return args.next();
+        }
+
+        if let Some(path) = arg.strip_prefix("--input=") {
+            return Some(path.to_string());
+        }
+    }
+
+    None
+}
+
+/// loads an input file of the calling crate, e.g. `load_input!("sample.txt")`.
+/// returns a `Result<String, InputError>`
+#[macro_export]
+macro_rules! load_input {
+    ($name:expr) => {
+        $crate::input::load(
+            None,
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            $name,
+        )
+    };
+}
+
+/// loads the puzzle input for a day's binary, honoring `--input PATH` on the
+/// command line. when given a parser (e.g. `input!("input.txt", parse_input)`)
+/// the input is parsed too. prints the error and exits if the input can't be
+/// loaded or parsed
+#[macro_export]
+macro_rules! input {
+    ($name:expr) => {
+        match $crate::input::load(
+            $crate::input::input_arg().as_deref(),
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            $name,
+        ) {
+            Ok(contents) => contents,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                ::std::process::exit(1);
+            }
+        }
+    };
+    ($name:expr, $parse:expr) => {
+        match $crate::input::load_with_path(
+            $crate::input::input_arg().as_deref(),
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            $name,
+        ) {
+            Ok((path, contents)) => match $parse(&contents) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("error: {}", $crate::ParseError::with_file(err, path));
+                    ::std::process::exit(1);
+                }
+            },
+            Err(err) => {
+                eprintln!("error: {}", err);
+                ::std::process::exit(1);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidates() {
+        let crate_dir = Path::new("/repo/2020/day10");
+
+        assert_eq!(
+            candidates(None, crate_dir, "input.txt"),
+            [PathBuf::from("/repo/2020/day10/input.txt")]
+        );
+        assert_eq!(
+            candidates(Some(OsStr::new("/inputs")), crate_dir, "input.txt"),
+            [
+                PathBuf::from("/inputs/2020/day10/input.txt"),
+                PathBuf::from("/repo/2020/day10/input.txt"),
+            ]
+        );
+        assert_eq!(
+            candidates(Some(OsStr::new("")), crate_dir, "input.txt"),
+            [PathBuf::from("/repo/2020/day10/input.txt")]
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        let contents = load(None, crate_dir, "Cargo.toml").unwrap();
+        assert!(contents.contains("aoc-common"));
+
+        let path = crate_dir.join("Cargo.toml");
+        let contents = load(path.to_str(), Path::new("/nowhere"), "input.txt").unwrap();
+        assert!(contents.contains("aoc-common"));
+    }
+
+    #[test]
+    fn test_find() {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        assert_eq!(
+            find(crate_dir, "Cargo.toml"),
+            Some(crate_dir.join("Cargo.toml"))
+        );
+        assert_eq!(find(crate_dir, "input.txt"), None);
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let err = load(Some("/nowhere/input.txt"), Path::new("."), "input.txt").unwrap_err();
+        assert!(err.to_string().contains("/nowhere/input.txt"));
+
+        let err = load(None, Path::new("/nowhere/2020/day10"), "input.txt").unwrap_err();
+        assert!(err.to_string().contains("/nowhere/2020/day10/input.txt"));
+    }
+}
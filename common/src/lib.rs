@@ -0,0 +1,36 @@
+//! # aoc-common
+//!
+//! Code shared between every year and day of the advent of code. Each day
+//! implements the [`Solution`] trait so that the `aoc` runner (and anything
+//! else, like benchmarks and tests) can parse and solve every day the same
+//! way without knowing anything about the day's own types.
+
+pub mod animation;
+mod answer;
+pub mod example;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod range;
+mod solution;
+
+pub use animation::{Color, Frame, Frames};
+pub use answer::Answer;
+pub use example::Example;
+pub use graph::Graph;
+pub use grid::Grid;
+pub use input::InputError;
+pub use ocr::OcrError;
+pub use parse::ParseError;
+pub use point::{Direction, Move, Point2, Point3, Turn};
+pub use range::RangeSet;
+pub use solution::Solution;
+
+/// the logging facade for debug output, so it only shows up when asked for
+/// (`aoc -v`) instead of mixing with the answers. every day can use it without
+/// depending on `log` itself
+pub use log;
@@ -0,0 +1,430 @@
+//! Number theory for the puzzles: gcd, modular inverses, the chinese
+//! remainder theorem, modular exponentiation and discrete logarithms.
+//!
+//! ```
+//! use aoc_common::math;
+//!
+//! // the timestamp where bus 17 leaves at t, bus 13 at t + 2 and 19 at t + 3
+//! assert_eq!(math::crt(&[(0, 17), (-2, 13), (-3, 19)]), Some((3417, 4199)));
+//!
+//! assert_eq!(math::pow_mod(7u128, 8, 20201227), 5764801);
+//! assert_eq!(math::discrete_log(7, 5764801, 20201227), Some(8));
+//! ```
+
+use std::collections::HashMap;
+
+/// an integer type the modular arithmetic works with. moduli have to be
+/// positive, negative numbers are reduced to their positive remainder
+pub trait ModInt: Copy {
+    /// returns the number as a modulus, panics if it isn't positive
+    fn modulus(self) -> u128;
+    /// returns `self mod m` as a number in `0..m`
+    fn reduce(self, m: u128) -> u128;
+    /// converts a number below the modulus back
+    fn from_reduced(n: u128) -> Self;
+}
+
+impl ModInt for u128 {
+    fn modulus(self) -> u128 {
+        assert!(self > 0, "the modulus has to be positive");
+        self
+    }
+
+    fn reduce(self, m: u128) -> u128 {
+        self % m
+    }
+
+    fn from_reduced(n: u128) -> Self {
+        n
+    }
+}
+
+impl ModInt for i128 {
+    fn modulus(self) -> u128 {
+        assert!(self > 0, "the modulus has to be positive, found {}", self);
+        self as u128
+    }
+
+    fn reduce(self, m: u128) -> u128 {
+        let remainder = self.unsigned_abs() % m;
+
+        if self < 0 && remainder > 0 {
+            m - remainder
+        } else {
+            remainder
+        }
+    }
+
+    fn from_reduced(n: u128) -> Self {
+        n as i128
+    }
+}
+
+/// returns the greatest common divisor of `a` and `b`, which is never
+/// negative
+pub fn gcd(a: i128, b: i128) -> i128 {
+    extended_gcd(a, b).0
+}
+
+/// returns `(g, x, y)` where `g` is the greatest common divisor of `a` and
+/// `b`, and `a * x + b * y = g`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// returns the least common multiple of `a` and `b`, which is never negative
+pub fn lcm(a: i128, b: i128) -> i128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    (a / gcd(a, b) * b).abs()
+}
+
+/// returns the `x` in `0..m` where `a * x = 1 (mod m)`, or `None` if `a` and
+/// `m` aren't coprime. `m` has to be positive
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// solves a system of congruences `x = residue (mod modulus)`, given as
+/// `(residue, modulus)` pairs. the moduli have to be positive but don't have
+/// to be coprime. returns the smallest non-negative solution and the period
+/// of the solutions (the lcm of the moduli), or `None` if the congruences
+/// contradict each other
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut solution = (0, 1);
+
+    for &(residue, modulus) in congruences {
+        assert!(
+            modulus > 0,
+            "the modulus has to be positive, found {}",
+            modulus
+        );
+
+        let (x, m) = solution;
+        let residue = residue.rem_euclid(modulus);
+
+        // x + m * k = residue (mod modulus), which can only be solved for k
+        // when the gcd of the moduli divides the difference
+        let g = gcd(m, modulus);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let k = mul_mod(diff / g, mod_inverse(m / g, step)?, step);
+        let period = (m / g)
+            .checked_mul(modulus)
+            .expect("the lcm of the moduli doesn't fit in an i128");
+
+        // both terms are below the period, so their sum fits in a u128
+        let x = (x as u128 + mul_mod(m, k, period) as u128) % period as u128;
+        solution = (x as i128, period);
+    }
+
+    Some(solution)
+}
+
+/// returns `a * b mod m` without overflowing
+pub fn mul_mod<T: ModInt>(a: T, b: T, m: T) -> T {
+    let m = m.modulus();
+    T::from_reduced(mul_reduced(a.reduce(m), b.reduce(m), m))
+}
+
+/// returns `base ^ exp mod m` without overflowing
+pub fn pow_mod<T: ModInt>(base: T, exp: u128, m: T) -> T {
+    let m = m.modulus();
+    T::from_reduced(pow_reduced(base.reduce(m), exp, m))
+}
+
+/// `a * b mod m` for `a` and `b` already below `m`
+fn mul_reduced(a: u128, b: u128, m: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // double and add, every step stays below m
+    let (mut a, mut b) = (a, b);
+    let mut result = 0;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_reduced(result, a, m);
+        }
+        a = add_reduced(a, a, m);
+        b >>= 1;
+    }
+
+    result
+}
+
+/// `a + b mod m` for `a` and `b` already below `m`
+fn add_reduced(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `base ^ exp mod m` for `base` already below `m`
+fn pow_reduced(mut base: u128, mut exp: u128, m: u128) -> u128 {
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_reduced(result, base, m);
+        }
+        base = mul_reduced(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// returns the smallest `x` where `base ^ x = target (mod m)`, or `None` if
+/// there isn't one. `base` has to be coprime with `m`.
+///
+/// uses baby-step giant-step, which takes about `sqrt(m)` time and memory:
+/// every `x` is `i * n - j` for some `j < n`, so the `target * base ^ j` are
+/// stored and looked up for every `base ^ (i * n)`
+pub fn discrete_log(base: u128, target: u128, m: u128) -> Option<u128> {
+    let m = m.modulus();
+    let (base, target) = (base % m, target % m);
+
+    if target == 1 % m {
+        return Some(0);
+    }
+
+    // the smallest n where n * n >= m
+    let mut n = (m as f64).sqrt() as u128;
+    while n.checked_mul(n).is_some_and(|square| square < m) {
+        n += 1;
+    }
+
+    // the largest j for each value, which gives the smallest x
+    let mut baby_steps = HashMap::new();
+    let mut value = target;
+    for j in 0..n {
+        baby_steps.insert(value, j);
+        value = mul_reduced(value, base, m);
+    }
+
+    let giant_step = pow_reduced(base, n, m);
+    let mut value = 1 % m;
+    for i in 1..=n {
+        value = mul_reduced(value, giant_step, m);
+
+        if let Some(j) = baby_steps.get(&value) {
+            let x = i * n - j;
+            // only a solution when base is invertible
+            return (pow_reduced(base, x, m) == target).then_some(x);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// the largest prime below 2^127 (2^127 - 1, a mersenne prime)
+    const M127: u128 = (1 << 127) - 1;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(17, 13), 1);
+
+        for a in -30..=30 {
+            for b in -30..=30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(a * x + b * y, g, "extended_gcd({}, {})", a, b);
+                assert!(g >= 0);
+                if g > 0 {
+                    assert_eq!((a % g, b % g), (0, 0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(7, 13), 91);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(1 << 62, 1 << 62), 1 << 62);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+
+        for m in 2..60 {
+            for a in 0..m {
+                match mod_inverse(a, m) {
+                    Some(x) => assert_eq!(a * x % m, 1, "{}^-1 mod {}", a, m),
+                    None => assert_ne!(gcd(a, m), 1, "{}^-1 mod {}", a, m),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        // the buses of 2020 day 13
+        assert_eq!(crt(&[(0, 17), (-2, 13), (-3, 19)]), Some((3417, 4199)));
+        assert_eq!(
+            crt(&[(0, 1789), (-1, 37), (-2, 47), (-3, 1889)]).map(|(x, _)| x),
+            Some(1202161486)
+        );
+
+        // moduli that aren't coprime
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(3, 10), (3, 10)]), Some((3, 10)));
+
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+
+        // every pair of small congruences, against a brute force search
+        for m1 in 1..13 {
+            for m2 in 1..13 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let period = lcm(m1, m2);
+                        let expected = (0..period).find(|x| x % m1 == r1 && x % m2 == r2);
+
+                        assert_eq!(
+                            crt(&[(r1, m1), (r2, m2)]),
+                            expected.map(|x| (x, period)),
+                            "x = {} (mod {}), x = {} (mod {})",
+                            r1,
+                            m1,
+                            r2,
+                            m2
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt_large_moduli() {
+        // the lcm is close to 2^126, and the products along the way overflow
+        let (p, q) = ((1 << 61) - 1, (1 << 63) - 25);
+        let (x, period) = crt(&[(12345, p), (-678, q)]).unwrap();
+
+        assert_eq!(period, p * q);
+        assert_eq!(x % p, 12345);
+        assert_eq!(x % q, q - 678);
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        assert_eq!(mul_mod(7i128, 8, 5), 1);
+        assert_eq!(mul_mod(-7i128, 8, 5), 4);
+        assert_eq!(mul_mod(i128::MIN, 1, 3), i128::MIN.rem_euclid(3));
+        assert_eq!(mul_mod(u128::MAX, u128::MAX, 7), 2);
+
+        // (2^127 - 2)^2 = (-1)^2 = 1 (mod 2^127 - 1)
+        assert_eq!(mul_mod(M127 - 1, M127 - 1, M127), 1);
+        assert_eq!(mul_mod(u128::MAX - 1, u128::MAX - 1, u128::MAX), 1);
+        assert_eq!(mul_mod(1u128 << 100, 1 << 100, M127), 1 << 73);
+
+        for m in 1..40u128 {
+            for a in 0..m {
+                for b in 0..m {
+                    assert_eq!(mul_reduced(a, b, m), a * b % m);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        assert_eq!(pow_mod(7u128, 8, 20201227), 5764801);
+        assert_eq!(pow_mod(2i128, 10, 1000), 24);
+        assert_eq!(pow_mod(-2i128, 3, 10), 2);
+        assert_eq!(pow_mod(5u128, 0, 7), 1);
+        assert_eq!(pow_mod(5u128, 0, 1), 0);
+        assert_eq!(pow_mod(0u128, 0, 7), 1);
+
+        // fermat's little theorem, with a modulus whose squares overflow
+        for a in [2, 3, 12345, M127 - 1] {
+            assert_eq!(pow_mod(a, M127 - 1, M127), 1);
+        }
+        assert_eq!(
+            pow_mod(3u128, u128::MAX, 1 << 64),
+            pow_mod(3, u64::MAX as u128, 1 << 64)
+        );
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        // the handshake of 2020 day 25
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+
+        assert_eq!(discrete_log(3, 1, 7), Some(0));
+        assert_eq!(discrete_log(2, 3, 7), None);
+        assert_eq!(discrete_log(5, 0, 1), Some(0));
+
+        // the smallest x, against a brute force search
+        for m in 2..60u128 {
+            for base in (1..m).filter(|&b| gcd(b as i128, m as i128) == 1) {
+                for target in 0..m {
+                    let expected = (0..m).find(|&x| pow_mod(base, x, m) == target);
+                    assert_eq!(
+                        discrete_log(base, target, m),
+                        expected,
+                        "log_{} {} (mod {})",
+                        base,
+                        target,
+                        m
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_discrete_log_large_modulus() {
+        let m = 1_000_000_007;
+        let target = pow_mod(5u128, 123_456_789, m);
+        let x = discrete_log(5, target, m).unwrap();
+
+        assert!(x <= 123_456_789);
+        assert_eq!(pow_mod(5, x, m), target);
+    }
+}
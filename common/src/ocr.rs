@@ -0,0 +1,225 @@
+//! Reads the block letters some puzzles draw their answer with, 4 pixels
+//! wide and 6 tall with a blank column between letters.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{Answer, Grid};
+
+/// the height of a letter in pixels
+pub const HEIGHT: usize = 6;
+
+/// the width of a letter in pixels, without the blank column after it
+pub const WIDTH: usize = 4;
+
+/// every letter that's shown up in a puzzle so far
+const GLYPHS: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+    (' ', ["....", "....", "....", "....", "....", "...."]),
+];
+
+/// a pixel of a grid that can be read, see [`read`]
+pub trait Pixel {
+    fn is_lit(&self) -> bool;
+}
+
+impl Pixel for bool {
+    fn is_lit(&self) -> bool {
+        *self
+    }
+}
+
+/// `#` (or a full block, which some people draw with) is lit, anything else
+/// is blank
+impl Pixel for char {
+    fn is_lit(&self) -> bool {
+        matches!(self, '#' | '█')
+    }
+}
+
+/// an error reading the letters of a grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// the grid isn't as tall as a letter
+    Height(usize),
+    /// the letter at `index` (0-based) doesn't look like any known letter,
+    /// `glyph` is its pixels
+    UnknownGlyph { index: usize, glyph: String },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Height(rows) => {
+                write!(f, "expected {} rows of pixels, found {}", HEIGHT, rows)
+            }
+            OcrError::UnknownGlyph { index, glyph } => {
+                write!(f, "letter {} isn't a known letter:\n{}", index + 1, glyph)
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// reads the letters drawn on `grid`. the letters start in the first column,
+/// and blank letters at the start and the end are ignored. a lit pixel in the
+/// blank column after a letter makes it an unknown letter
+pub fn read<T: Pixel>(grid: &Grid<T>) -> Result<String, OcrError> {
+    if grid.rows() != HEIGHT {
+        return Err(OcrError::Height(grid.rows()));
+    }
+
+    let letters = grid.cols().div_ceil(WIDTH + 1);
+
+    let text = (0..letters)
+        .map(|index| {
+            let lit = |row: usize, col: usize| {
+                grid.get((row, index * (WIDTH + 1) + col))
+                    .is_some_and(Pixel::is_lit)
+            };
+
+            // the blank column is only part of the glyph when it isn't blank,
+            // so the glyph is too wide to match any letter
+            let width = match (0..HEIGHT).any(|row| lit(row, WIDTH)) {
+                true => WIDTH + 1,
+                false => WIDTH,
+            };
+
+            let glyph: Vec<String> = (0..HEIGHT)
+                .map(|row| {
+                    (0..width)
+                        .map(|col| if lit(row, col) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+
+            GLYPHS
+                .iter()
+                .find(|(_, pixels)| glyph.iter().zip(pixels).all(|(a, b)| a == b))
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| OcrError::UnknownGlyph {
+                    index,
+                    glyph: glyph.join("\n"),
+                })
+        })
+        .collect::<Result<String, OcrError>>()?;
+
+    Ok(text.trim().to_string())
+}
+
+/// reads the letters drawn on `grid`, or returns the pixels themselves when
+/// they can't be read so they're still shown to whoever runs the day
+pub fn read_or_pixels<T: Pixel>(grid: &Grid<T>) -> Answer {
+    match read(grid) {
+        Ok(letters) => Answer::Text(letters),
+        Err(err) => {
+            log::warn!("{}", err);
+            Answer::Pixels(grid.map(|_, pixel| pixel.is_lit()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// draws `letters` the way a puzzle would, with a blank column after each
+    fn draw(letters: &str) -> Grid<char> {
+        let glyphs: Vec<&[&str; HEIGHT]> = letters
+            .chars()
+            .map(|letter| &GLYPHS.iter().find(|(l, _)| *l == letter).unwrap().1)
+            .collect();
+
+        let rows = (0..HEIGHT)
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .flat_map(|glyph| glyph[row].chars().chain(['.']))
+                    .collect()
+            })
+            .collect();
+
+        Grid::from_rows(rows)
+    }
+
+    #[test]
+    fn test_read() {
+        let alphabet = "ABCEFGHIJKLOPRSUZ";
+        assert_eq!(read(&draw(alphabet)), Ok(alphabet.to_string()));
+
+        // without the blank column after the last letter
+        let grid = Grid::parse(
+            ".##..###.\n#..#.#..#\n#..#.###.\n####.#..#\n#..#.#..#\n#..#.###.",
+            "#.",
+        )
+        .unwrap();
+        assert_eq!(read(&grid), Ok("AB".to_string()));
+
+        let pixels = draw("  HI ").map(|_, c| c.is_lit());
+        assert_eq!(read(&pixels), Ok("HI".to_string()));
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let mut grid = draw("AB");
+        grid[(0, 8)] = '#';
+
+        assert_eq!(
+            read(&grid),
+            Err(OcrError::UnknownGlyph {
+                index: 1,
+                glyph: "####\n#..#\n###.\n#..#\n#..#\n###.".to_string()
+            })
+        );
+        assert_eq!(
+            read(&Grid::from_rows(vec![vec![true; 4]; 5])),
+            Err(OcrError::Height(5))
+        );
+
+        // a pixel between two letters belongs to neither of them
+        let mut grid = draw("LI");
+        grid[(5, 4)] = '#';
+
+        assert_eq!(
+            read(&grid),
+            Err(OcrError::UnknownGlyph {
+                index: 0,
+                glyph: "#....\n#....\n#....\n#....\n#....\n#####".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_or_pixels() {
+        let pixels = draw("CZ").map(|_, c| c.is_lit());
+        assert_eq!(read_or_pixels(&pixels), Answer::from("CZ"));
+        assert_eq!(read_or_pixels(&draw("CZ")), Answer::from("CZ"));
+
+        let pixels = Grid::from_rows(vec![vec![true; 4]; HEIGHT]);
+        assert_eq!(read_or_pixels(&pixels), Answer::Pixels(pixels));
+
+        let mut screen = draw("CZ");
+        screen[(0, 4)] = '#';
+        assert_eq!(
+            read_or_pixels(&screen),
+            Answer::Pixels(screen.map(|_, c| c.is_lit()))
+        );
+    }
+}
@@ -0,0 +1,340 @@
+//! Errors and helpers for parsing puzzle inputs.
+//!
+//! Parsers mostly work on slices of the input (from `lines()`, `split()`,
+//! `trim()`, ...), so [`ParseError::at`] works out the line and column of
+//! the offending text from where the slice points into the input.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// an error parsing a puzzle input, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// the file the input was read from, if known
+    pub file: Option<String>,
+    /// the 1-based line of the offending text, 0 if unknown
+    pub line: usize,
+    /// the 1-based column of the offending text, 0 if unknown
+    pub column: usize,
+    /// the offending text
+    pub text: String,
+    /// what went wrong
+    pub message: String,
+}
+
+impl ParseError {
+    /// returns an error for `text`, which should be a slice of `input`. if it
+    /// isn't, the line and column are left unknown
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize).wrapping_sub(start);
+
+        let (line, column) = if offset <= input.len() && input.is_char_boundary(offset) {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+            (
+                before.matches('\n').count() + 1,
+                before[line_start..].chars().count() + 1,
+            )
+        } else {
+            (0, 0)
+        };
+
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// returns an error that doesn't point at a specific part of the input
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column: 0,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// sets the file the input was read from
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+
+        if self.line > 0 {
+            write!(f, "{}:{}:{}: ", file, self.line, self.column)?;
+        } else {
+            write!(f, "{}: ", file)?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, " ({:?})", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// parses `text`, a slice of `input`, ignoring surrounding whitespace (e.g. a
+/// trailing newline or `\r`)
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    let text = text.trim();
+
+    text.parse().map_err(|_| {
+        ParseError::at(
+            input,
+            text,
+            format!("expected a {}", short_type_name::<T>()),
+        )
+    })
+}
+
+/// returns `Some` item of an iterator over slices of `input`, or an error
+/// pointing at `context` (usually the line being parsed) if it's missing
+pub fn next<'a>(
+    input: &str,
+    context: &str,
+    item: Option<&'a str>,
+    what: &str,
+) -> Result<&'a str, ParseError> {
+    item.ok_or_else(|| ParseError::at(input, context, format!("missing {}", what)))
+}
+
+/// parses a row of a character map, `line` should be a slice of `input`.
+/// errors on characters that aren't in `allowed`
+pub fn row(input: &str, line: &str, allowed: &str) -> Result<Vec<char>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            if allowed.contains(c) {
+                Ok(c)
+            } else {
+                let text = &line[i..i + c.len_utf8()];
+                Err(ParseError::at(
+                    input,
+                    text,
+                    format!("expected one of {:?}", allowed),
+                ))
+            }
+        })
+        .collect()
+}
+
+/// parses a row of single digits, `line` should be a slice of `input`
+pub fn digits(input: &str, line: &str) -> Result<Vec<usize>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                ParseError::at(input, &line[i..i + c.len_utf8()], "expected a digit")
+            })
+        })
+        .collect()
+}
+
+/// splits `text`, a slice of `input`, into a key and a value on the first
+/// `sep`, trimming the whitespace around both. e.g. `"ecl:gry"` with `":"`,
+/// or `"forward 5"` with `" "`
+pub fn pair<'a>(input: &str, text: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let text = text.trim();
+
+    text.split_once(sep)
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| ParseError::at(input, text, format!("expected 'key{}value'", sep)))
+}
+
+/// splits `input` into records, groups of lines that are separated by one or
+/// more blank lines (e.g. passports or tiles). `\r\n` line endings, lines of
+/// only whitespace and blank lines at the start or end are all fine
+pub fn records(input: &str) -> impl Iterator<Item = Record<'_>> {
+    let mut lines = input.lines().peekable();
+
+    std::iter::from_fn(move || {
+        while lines.peek()?.trim().is_empty() {
+            lines.next();
+        }
+
+        let first = lines.next()?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+            last = line;
+        }
+
+        let start = first.as_ptr() as usize - input.as_ptr() as usize;
+        let end = last.as_ptr() as usize - input.as_ptr() as usize + last.len();
+
+        Some(Record {
+            input,
+            text: &input[start..end],
+        })
+    })
+}
+
+/// a group of consecutive lines in an input, see [`records`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    input: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Record<'a> {
+    /// the text of the record, which is a slice of the input
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// the lines of the record, without their line endings
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    /// splits the record into its first line (e.g. `"Tile 2311:"`) and the
+    /// rest of its lines
+    pub fn header(&self) -> (&'a str, std::str::Lines<'a>) {
+        let mut lines = self.lines();
+        (lines.next().unwrap_or_default(), lines)
+    }
+
+    /// every whitespace separated `key:value` field of the record, no matter
+    /// which line it's on
+    pub fn fields(&self) -> impl Iterator<Item = Result<(&'a str, &'a str), ParseError>> + 'a {
+        let input = self.input;
+        self.text
+            .split_whitespace()
+            .map(move |field| pair(input, field, ":"))
+    }
+
+    /// every line of the record as a `key<sep>value` pair
+    pub fn entries(
+        &self,
+        sep: &'a str,
+    ) -> impl Iterator<Item = Result<(&'a str, &'a str), ParseError>> + 'a {
+        let input = self.input;
+        self.lines().map(move |line| pair(input, line, sep))
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "1,2\n3,x\n";
+        let text = input.lines().nth(1).unwrap().split(',').nth(1).unwrap();
+
+        let err = ParseError::at(input, text, "expected a number");
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+        assert_eq!(
+            err.with_file("input.txt").to_string(),
+            "input.txt:2:3: expected a number (\"x\")"
+        );
+
+        // text from somewhere else has no position
+        let err = ParseError::at(input, "x", "expected a number");
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(err.to_string(), "<input>: expected a number (\"x\")");
+    }
+
+    #[test]
+    fn test_number() {
+        let input = "12\r\n-3\nab\n";
+        let lines: Vec<&str> = input.split('\n').collect();
+
+        assert_eq!(number::<u32>(input, lines[0]), Ok(12));
+        assert_eq!(number::<i32>(input, lines[1]), Ok(-3));
+
+        let err = number::<u32>(input, lines[1]).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 1, "expected a u32")
+        );
+
+        let err = number::<usize>(input, lines[2]).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (3, "ab"));
+    }
+
+    #[test]
+    fn test_next() {
+        let input = "a b\nc";
+        let line = input.lines().nth(1).unwrap();
+        let mut split = line.split(' ');
+
+        assert_eq!(next(input, line, split.next(), "command"), Ok("c"));
+
+        let err = next(input, line, split.next(), "value").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 1, "missing value")
+        );
+    }
+
+    #[test]
+    fn test_row() {
+        let input = "..#\n.x.\n";
+        let lines: Vec<&str> = input.lines().collect();
+
+        assert_eq!(row(input, lines[0], ".#"), Ok(vec!['.', '.', '#']));
+
+        let err = row(input, lines[1], ".#").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn test_digits() {
+        let input = "219\n3a\n";
+        let lines: Vec<&str> = input.lines().collect();
+
+        assert_eq!(digits(input, lines[0]), Ok(vec![2, 1, 9]));
+
+        let err = digits(input, lines[1]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "a"));
+    }
+
+    #[test]
+    fn test_records() {
+        let input = "\r\na:1 b:2\r\nc:3\r\n\r\n \r\nfoo bar\r\n\r\n\r\n";
+        let groups: Vec<Record> = records(input).collect();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            groups[0].lines().collect::<Vec<&str>>(),
+            vec!["a:1 b:2", "c:3"]
+        );
+        assert_eq!(
+            groups[0].fields().collect::<Result<Vec<_>, _>>(),
+            Ok(vec![("a", "1"), ("b", "2"), ("c", "3")])
+        );
+        assert_eq!(groups[1].header().0, "foo bar");
+        assert_eq!(
+            groups[1].entries(" ").collect::<Result<Vec<_>, _>>(),
+            Ok(vec![("foo", "bar")])
+        );
+
+        // errors still point into the input
+        let err = groups[1].fields().next().unwrap().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (6, 1, "foo"));
+
+        assert_eq!(records("").count(), 0);
+        assert_eq!(records("\n\n").count(), 0);
+    }
+}
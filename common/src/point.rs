@@ -0,0 +1,458 @@
+//! Integer points, directions and turns for the puzzles that move something
+//! around instead of working on a [`Grid`](crate::Grid). `x` grows to the
+//! east and `y` to the north, the opposite of a grid's rows.
+//!
+//! ```
+//! use aoc_common::{Direction, Move, Point2};
+//!
+//! let input = "F10\nN3\nF7\nR90\nF11";
+//! let mut ship = Point2::ORIGIN;
+//! let mut heading = Direction::East;
+//!
+//! for line in input.lines() {
+//!     match Move::parse(input, line).unwrap() {
+//!         Move::Go(direction, n) => ship += direction.offset() * n,
+//!         Move::Turn(turn) => heading = heading.turn(turn),
+//!         Move::Forward(n) => ship += heading.offset() * n,
+//!     }
+//! }
+//!
+//! assert_eq!(ship, Point2::new(17, -8));
+//! assert_eq!(ship.manhattan(Point2::ORIGIN), 25);
+//! ```
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::parse::{self, ParseError};
+
+/// a point on a plane, or the offset between two
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// a point in space, or the offset between two
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    /// the distance to `other` moving only along the axes
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// the distance to `other` when diagonal steps are allowed
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// the point rotated around the origin by `turn`
+    pub fn rotate(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Straight => self,
+            Turn::Left => Point2::new(-self.y, self.x),
+            Turn::Around => -self,
+            Turn::Right => Point2::new(self.y, -self.x),
+        }
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// the distance to `other` moving only along the axes
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// the distance to `other` when diagonal steps are allowed
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// the point rotated a quarter turn around the x axis, counterclockwise
+    /// when looking from the positive side of the axis
+    pub fn rotate_x(self) -> Self {
+        Point3::new(self.x, -self.z, self.y)
+    }
+
+    /// the point rotated a quarter turn around the y axis, see [`Point3::rotate_x`]
+    pub fn rotate_y(self) -> Self {
+        Point3::new(self.z, self.y, -self.x)
+    }
+
+    /// the point rotated a quarter turn around the z axis, see [`Point3::rotate_x`]
+    pub fn rotate_z(self) -> Self {
+        Point3::new(-self.y, self.x, self.z)
+    }
+
+    /// the point in each of the 24 ways space can be rotated (for scanners
+    /// that don't know which way they face), starting with the point itself
+    pub fn orientations(self) -> [Point3; 24] {
+        // point each of the 6 faces up the z axis, then spin around it
+        let faces = [
+            self,
+            self.rotate_x(),
+            self.rotate_x().rotate_x(),
+            self.rotate_x().rotate_x().rotate_x(),
+            self.rotate_y(),
+            self.rotate_y().rotate_y().rotate_y(),
+        ];
+
+        let mut orientations = [self; 24];
+        for (i, face) in faces.into_iter().enumerate() {
+            let mut point = face;
+            for spin in 0..4 {
+                orientations[i * 4 + spin] = point;
+                point = point.rotate_z();
+            }
+        }
+        orientations
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, n: i64) -> $point {
+                $point { $($field: self.$field * n),* }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+/// a change of direction, in quarter turns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Straight,
+    Left,
+    Around,
+    Right,
+}
+
+impl Turn {
+    /// the turn by `degrees` counterclockwise (to the left), `None` if it
+    /// isn't a multiple of 90
+    pub fn from_degrees(degrees: i64) -> Option<Turn> {
+        match degrees.rem_euclid(360) {
+            0 => Some(Turn::Straight),
+            90 => Some(Turn::Left),
+            180 => Some(Turn::Around),
+            270 => Some(Turn::Right),
+            _ => None,
+        }
+    }
+
+    /// the number of quarter turns to the left
+    fn quarters(self) -> u8 {
+        self as u8
+    }
+}
+
+/// one of the four compass directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// every direction, clockwise from north
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// the direction for a compass letter (`N`, `E`, `S`, `W`) or a relative
+    /// one (`U`, `R`, `D`, `L`)
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'N' | 'U' => Some(Direction::North),
+            'E' | 'R' => Some(Direction::East),
+            'S' | 'D' => Some(Direction::South),
+            'W' | 'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// parses `text`, a slice of `input`, as a direction letter (see
+    /// [`Direction::from_char`]) followed by a distance, e.g. `U7` or `N10`
+    pub fn parse_move(input: &str, text: &str) -> Result<(Direction, i64), ParseError> {
+        let (letter, distance) = split_letter(input, text)?;
+        let direction = Direction::from_char(letter)
+            .ok_or_else(|| ParseError::at(input, text, "expected a direction (NESW or URDL)"))?;
+
+        Ok((direction, unsigned(input, distance)?))
+    }
+
+    /// a step of 1 in this direction
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::North => Point2::new(0, 1),
+            Direction::East => Point2::new(1, 0),
+            Direction::South => Point2::new(0, -1),
+            Direction::West => Point2::new(-1, 0),
+        }
+    }
+
+    /// the direction after turning by `turn`
+    pub fn turn(self, turn: Turn) -> Direction {
+        // counterclockwise is backwards through `ALL`
+        Direction::ALL[(self as usize + 4 - turn.quarters() as usize) % 4]
+    }
+
+    /// the direction after turning left
+    pub fn left(self) -> Direction {
+        self.turn(Turn::Left)
+    }
+
+    /// the direction after turning right
+    pub fn right(self) -> Direction {
+        self.turn(Turn::Right)
+    }
+
+    /// the opposite direction
+    pub fn opposite(self) -> Direction {
+        self.turn(Turn::Around)
+    }
+}
+
+/// a navigation instruction that can be relative to the way something faces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Move {
+    /// move in a compass direction, whichever way it faces
+    Go(Direction, i64),
+    /// turn without moving
+    Turn(Turn),
+    /// move the way it faces
+    Forward(i64),
+}
+
+impl Move {
+    /// parses `text`, a slice of `input`, as a compass move (`N`, `E`, `S` or
+    /// `W` and a distance), a turn (`L` or `R` and a multiple of 90 degrees)
+    /// or a move forward (`F` and a distance), e.g. `N3`, `R90` or `F10`
+    pub fn parse(input: &str, text: &str) -> Result<Move, ParseError> {
+        let (letter, value) = split_letter(input, text)?;
+
+        match letter {
+            'N' | 'E' | 'S' | 'W' => Ok(Move::Go(
+                Direction::from_char(letter).unwrap(),
+                unsigned(input, value)?,
+            )),
+            'L' | 'R' => {
+                let degrees = unsigned(input, value)?;
+                let degrees = if letter == 'L' { degrees } else { -degrees };
+
+                Turn::from_degrees(degrees).map(Move::Turn).ok_or_else(|| {
+                    ParseError::at(input, value, "expected a multiple of 90 degrees")
+                })
+            }
+            'F' => Ok(Move::Forward(unsigned(input, value)?)),
+            _ => Err(ParseError::at(
+                input,
+                text,
+                "expected a move (NESW, LR or F)",
+            )),
+        }
+    }
+}
+
+/// splits the letter at the start of a move from the number after it
+fn split_letter<'a>(input: &str, text: &'a str) -> Result<(char, &'a str), ParseError> {
+    let text = text.trim();
+    let letter = text
+        .chars()
+        .next()
+        .ok_or_else(|| ParseError::at(input, text, "expected a move"))?;
+
+    Ok((letter, &text[letter.len_utf8()..]))
+}
+
+/// parses a distance or an angle. the letter before it already says which
+/// way to go, so it can't be negative
+fn unsigned(input: &str, text: &str) -> Result<i64, ParseError> {
+    parse::number::<u32>(input, text).map(i64::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2() {
+        let a = Point2::new(3, -4);
+        let b = Point2::from((-1, 2));
+
+        assert_eq!(a + b, Point2::new(2, -2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(a * 3, Point2::new(9, -12));
+        assert_eq!(-a, Point2::new(-3, 4));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+
+        assert_eq!(a.manhattan(Point2::ORIGIN), 7);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(b.chebyshev(b), 0);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let p = Point2::new(10, 4);
+
+        assert_eq!(p.rotate(Turn::Straight), p);
+        assert_eq!(p.rotate(Turn::Left), Point2::new(-4, 10));
+        assert_eq!(p.rotate(Turn::Around), Point2::new(-10, -4));
+        assert_eq!(p.rotate(Turn::Right), Point2::new(4, -10));
+        assert_eq!(p.rotate(Turn::Left).rotate(Turn::Right), p);
+
+        // turning a direction turns its offset the same way
+        for direction in Direction::ALL {
+            for turn in [Turn::Straight, Turn::Left, Turn::Around, Turn::Right] {
+                assert_eq!(
+                    direction.turn(turn).offset(),
+                    direction.offset().rotate(turn)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_point3() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::from((4, 0, -1));
+
+        assert_eq!(a + b, Point3::new(5, -2, 2));
+        assert_eq!(a - b, Point3::new(-3, -2, 4));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 4);
+
+        assert_eq!(a.rotate_x(), Point3::new(1, -3, -2));
+        assert_eq!(a.rotate_y(), Point3::new(3, -2, -1));
+        assert_eq!(a.rotate_z(), Point3::new(2, 1, 3));
+        assert_eq!(a.rotate_z().rotate_z().rotate_z().rotate_z(), a);
+
+        let mut orientations = Point3::new(1, 2, 3).orientations().to_vec();
+        assert_eq!(orientations[0], Point3::new(1, 2, 3));
+        orientations.sort();
+        orientations.dedup();
+        assert_eq!(orientations.len(), 24);
+        // rotations keep the same hand, so the mirror image isn't one of them
+        assert!(!orientations.contains(&Point3::new(2, 1, 3)));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::North.right(), Direction::East);
+        assert_eq!(Direction::North.left(), Direction::West);
+        assert_eq!(Direction::West.right(), Direction::North);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert_eq!(Direction::South.turn(Turn::Straight), Direction::South);
+
+        assert_eq!(Turn::from_degrees(90), Some(Turn::Left));
+        assert_eq!(Turn::from_degrees(-90), Some(Turn::Right));
+        assert_eq!(Turn::from_degrees(540), Some(Turn::Around));
+        assert_eq!(Turn::from_degrees(45), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |text| Direction::parse_move(text, text);
+        assert_eq!(parse("U7"), Ok((Direction::North, 7)));
+        assert_eq!(parse("L12"), Ok((Direction::West, 12)));
+        assert_eq!(parse("S3"), Ok((Direction::South, 3)));
+        assert!(parse("X3").is_err());
+        assert!(parse("R").is_err());
+        assert!(parse("R-5").is_err());
+        assert!(parse("").is_err());
+
+        let parse = |text| Move::parse(text, text);
+        assert_eq!(parse("N3"), Ok(Move::Go(Direction::North, 3)));
+        assert_eq!(parse("F10"), Ok(Move::Forward(10)));
+        assert_eq!(parse("L270"), Ok(Move::Turn(Turn::Right)));
+        assert_eq!(parse("R90"), Ok(Move::Turn(Turn::Right)));
+        assert!(parse("R45").is_err());
+        assert!(parse("F-10").is_err());
+        assert!(parse("L-90").is_err());
+        assert!(parse("U3").is_err());
+    }
+}
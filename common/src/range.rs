@@ -0,0 +1,343 @@
+//! Sets of integers stored as the inclusive ranges that make them up.
+//!
+//! ```
+//! use aoc_common::RangeSet;
+//!
+//! let rule: RangeSet<i32> = "1-3 or 5-7".parse().unwrap();
+//! assert!(rule.contains(6) && !rule.contains(4));
+//!
+//! let both = rule.union(&RangeSet::from(4..=4));
+//! assert_eq!(both.to_string(), "1-7");
+//! assert_eq!(both.difference(&rule).len(), 1);
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// an integer type a [`RangeSet`] can hold
+pub trait RangeInt: Copy + Ord + FromStr + fmt::Display {
+    /// the next integer, unless `self` is the largest one
+    fn next(self) -> Option<Self>;
+    /// the previous integer, unless `self` is the smallest one
+    fn prev(self) -> Option<Self>;
+    /// the number of integers from `lo` to `hi`, both included
+    fn count(lo: Self, hi: Self) -> u128;
+}
+
+macro_rules! impl_range_int {
+    ($($t:ty),*) => {
+        $(
+            impl RangeInt for $t {
+                fn next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(lo: Self, hi: Self) -> u128 {
+                    (hi.abs_diff(lo) as u128).saturating_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_range_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// a set of integers, stored as sorted inclusive ranges that neither overlap
+/// nor touch (so `1-3` and `4-6` are stored as `1-6`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: RangeInt> RangeSet<T> {
+    /// creates an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// sorts `ranges` and merges the ones that overlap or touch
+    fn normalized(mut ranges: Vec<(T, T)>) -> Self {
+        ranges.retain(|(lo, hi)| lo <= hi);
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+        for (lo, hi) in ranges {
+            match merged.last_mut() {
+                Some(last) if last.1.next().is_none_or(|after| lo <= after) => {
+                    last.1 = last.1.max(hi);
+                }
+                _ => merged.push((lo, hi)),
+            }
+        }
+
+        RangeSet { ranges: merged }
+    }
+
+    /// parses `text`, a slice of `input`, as ranges separated by `or`, e.g.
+    /// `1-3 or 5-7`. a range can also be a single number
+    pub fn parse(input: &str, text: &str) -> Result<Self, ParseError> {
+        let ranges = text
+            .split(" or ")
+            .map(|range| {
+                let range = range.trim();
+
+                // the dash of a negative start isn't the separator
+                let (lo, hi) = match range.char_indices().skip(1).find(|&(_, c)| c == '-') {
+                    Some((i, _)) => (&range[..i], &range[i + 1..]),
+                    None => (range, range),
+                };
+                let (lo, hi): (T, T) = (parse::number(input, lo)?, parse::number(input, hi)?);
+
+                if lo > hi {
+                    return Err(ParseError::at(
+                        input,
+                        range,
+                        "the range ends before it starts",
+                    ));
+                }
+
+                Ok((lo, hi))
+            })
+            .collect::<Result<Vec<(T, T)>, ParseError>>()?;
+
+        Ok(Self::normalized(ranges))
+    }
+
+    /// adds every integer in `range`
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range.into_inner());
+        *self = Self::normalized(ranges);
+    }
+
+    /// returns true if `value` is in the set
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, hi)| hi < value);
+        self.ranges.get(i).is_some_and(|&(lo, _)| lo <= value)
+    }
+
+    /// the number of integers in the set
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().fold(0, |len: u128, &(lo, hi)| {
+            len.saturating_add(T::count(lo, hi))
+        })
+    }
+
+    /// returns true if the set has no integers
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// the ranges that make up the set, from the lowest
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(lo, hi)| lo..=hi)
+    }
+
+    /// the integers in either set
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized([&self.ranges[..], &other.ranges[..]].concat())
+    }
+
+    /// the integers in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.ranges, &other.ranges);
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < a.len() && j < b.len() {
+            let lo = a[i].0.max(b[j].0);
+            let hi = a[i].1.min(b[j].1);
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+
+            // the range that ends first can't overlap anything else
+            if a[i].1 < b[j].1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// the integers in this set that aren't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let cuts = &other.ranges;
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for &(lo, hi) in &self.ranges {
+            // skip the cuts that end before this range
+            while j < cuts.len() && cuts[j].1 < lo {
+                j += 1;
+            }
+
+            // what's left of the range starts at `start`, each cut that
+            // overlaps it keeps the part before the cut
+            let mut start = Some(lo);
+            for &(cut_lo, cut_hi) in cuts[j..].iter().take_while(|(cut_lo, _)| *cut_lo <= hi) {
+                let Some(from) = start else { break };
+                if cut_lo > from {
+                    ranges.push((from, cut_lo.prev().unwrap()));
+                }
+                start = cut_hi.next().filter(|&after| after <= hi);
+            }
+
+            if let Some(from) = start {
+                ranges.push((from, hi));
+            }
+        }
+
+        RangeSet { ranges }
+    }
+}
+
+impl<T: RangeInt> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::normalized(vec![range.into_inner()])
+    }
+}
+
+impl<T: RangeInt> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        Self::normalized(ranges.into_iter().map(RangeInclusive::into_inner).collect())
+    }
+}
+
+impl<T: RangeInt> FromStr for RangeSet<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, s)
+    }
+}
+
+/// the ranges separated by `or`, the way they're parsed
+impl<T: RangeInt> fmt::Display for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, &(lo, hi)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, " or ")?;
+            }
+            match lo == hi {
+                true => write!(f, "{}", lo)?,
+                false => write!(f, "{}-{}", lo, hi)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(s: &str) -> RangeSet<i32> {
+        s.parse().unwrap()
+    }
+
+    /// the sets of 0..16 picked by the bits of `mask`, to compare against
+    fn from_mask(mask: u16) -> RangeSet<i32> {
+        (0..16)
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| i..=i)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            set("1-3 or 5-7").ranges().collect::<Vec<_>>(),
+            [1..=3, 5..=7]
+        );
+        assert_eq!(set("5-7 or 1-3 or 4-4").to_string(), "1-7");
+        assert_eq!(set("-5--2 or 9 or 0-1").to_string(), "-5--2 or 0-1 or 9");
+        assert_eq!(set("10-20 or 12-15 or 30-40").to_string(), "10-20 or 30-40");
+
+        assert!("3-1".parse::<RangeSet<i32>>().is_err());
+        assert!("1-a".parse::<RangeSet<i32>>().is_err());
+        assert!("1-3 and 5-7".parse::<RangeSet<i32>>().is_err());
+        assert!("-1-3".parse::<RangeSet<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let rule = set("1-3 or 5-7");
+
+        assert_eq!(
+            (0..9).filter(|&n| rule.contains(n)).collect::<Vec<_>>(),
+            [1, 2, 3, 5, 6, 7]
+        );
+        assert_eq!(rule.len(), 6);
+        assert!(!rule.is_empty());
+        assert!(RangeSet::<i32>::new().is_empty());
+        assert!(!RangeSet::<i32>::new().contains(0));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut ranges = RangeSet::new();
+        ranges.insert(5..=7);
+        ranges.insert(1..=3);
+        assert_eq!(ranges.to_string(), "1-3 or 5-7");
+
+        ranges.insert(4..=4);
+        assert_eq!(ranges.to_string(), "1-7");
+
+        // an empty range changes nothing
+        #[allow(clippy::reversed_empty_ranges)]
+        ranges.insert(10..=9);
+        assert_eq!(ranges.len(), 7);
+    }
+
+    #[test]
+    fn test_extremes() {
+        let all = RangeSet::from(u8::MIN..=u8::MAX);
+        assert_eq!(all.len(), 256);
+        assert!(all.contains(255));
+
+        let top = RangeSet::from(250..=255u8).union(&RangeSet::from(0..=249));
+        assert_eq!(top, all);
+        assert_eq!(all.difference(&RangeSet::from(0..=254)).to_string(), "255");
+        assert_eq!(all.difference(&RangeSet::from(1..=255)).to_string(), "0");
+        assert!(all.difference(&all).is_empty());
+
+        assert_eq!(RangeSet::from(i128::MIN..=i128::MAX).len(), u128::MAX);
+        assert_eq!(RangeSet::from(0..=u128::MAX).len(), u128::MAX);
+    }
+
+    #[test]
+    fn test_operations() {
+        // every pair of a few sets of 0..16, checked against the sets' bits
+        let masks = [
+            0x0000, 0xffff, 0x00f0, 0x0f0f, 0xa5a5, 0x1234, 0x8001, 0x7ffe, 0x0ff0, 0x3c3c,
+        ];
+
+        for &a in &masks {
+            for &b in &masks {
+                let (sa, sb) = (from_mask(a), from_mask(b));
+
+                assert_eq!(sa.union(&sb), from_mask(a | b));
+                assert_eq!(sa.intersection(&sb), from_mask(a & b));
+                assert_eq!(sa.difference(&sb), from_mask(a & !b));
+                assert_eq!(sa.len(), a.count_ones() as u128);
+                assert!((0..16).all(|i| sa.contains(i) == (a & (1 << i) != 0)));
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Answer, Example, Frames, ParseError};
+
+/// a single day of the advent of code.
+///
+/// the puzzle input is parsed once into `Input` and then handed to each part,
+/// so parsing and solving can be timed and tested separately
+pub trait Solution {
+    /// the parsed puzzle input shared by both parts
+    type Input;
+
+    /// the worked examples from the puzzle description, see
+    /// [`example`](crate::example)
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// parses the contents of the puzzle input
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// solves part 1 of the puzzle
+    fn part1(input: &Self::Input) -> Answer;
+
+    /// solves part 2 of the puzzle
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// returns every generation of the simulation behind `part`, for the days
+    /// that are step simulations, see [`animation`](crate::animation)
+    fn animate(_input: &Self::Input, _part: u8) -> Option<Frames<'_>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .map(|line| crate::parse::number(input, line))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i32>().into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Sum::parse("1\n2\n3\n").unwrap();
+
+        assert_eq!(Sum::part1(&input), Answer::Signed(6));
+        assert_eq!(Sum::part2(&input), Answer::Unsolved);
+        assert!(Sum::animate(&input, 1).is_none());
+
+        let err = Sum::parse("1\nx\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
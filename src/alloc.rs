@@ -0,0 +1,181 @@
+//! A global allocator that counts every allocation, so `aoc bench` can report
+//! how much memory each phase of a day uses next to how long it takes. It's
+//! opt-in since the counters slow down every allocation a little: build the
+//! runner with `--features count-allocs` to install it.
+//!
+//! The counters are shared by every thread, so the numbers are only accurate
+//! when a single day runs at a time (as it does in `aoc bench`).
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: Counting = Counting::new();
+
+/// the allocations made while running a closure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocs {
+    /// the number of allocations, including reallocations
+    pub allocations: u64,
+    /// the total number of bytes allocated, a reallocation counts its new size
+    pub bytes: u64,
+    /// the most bytes that were live at the same time, not counting what was
+    /// already allocated before
+    pub peak: u64,
+}
+
+/// wraps the system allocator and counts every allocation
+pub struct Counting {
+    allocations: AtomicU64,
+    bytes: AtomicU64,
+    live: AtomicU64,
+    peak: AtomicU64,
+}
+
+// unused unless the allocator is installed
+#[cfg_attr(not(feature = "count-allocs"), allow(dead_code))]
+impl Counting {
+    pub const fn new() -> Self {
+        Counting {
+            allocations: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            live: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        }
+    }
+
+    fn record_alloc(&self, size: usize) {
+        let size = size as u64;
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+
+        let live = self.live.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(&self, size: usize) {
+        self.live.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+
+    /// runs `f` and returns the allocations it made
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Allocs) {
+        let allocations = self.allocations.load(Ordering::Relaxed);
+        let bytes = self.bytes.load(Ordering::Relaxed);
+        let live = self.live.load(Ordering::Relaxed);
+        self.peak.store(live, Ordering::Relaxed);
+
+        let value = f();
+
+        let allocs = Allocs {
+            allocations: self.allocations.load(Ordering::Relaxed) - allocations,
+            bytes: self.bytes.load(Ordering::Relaxed) - bytes,
+            peak: self.peak.load(Ordering::Relaxed).saturating_sub(live),
+        };
+
+        (value, allocs)
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.record_dealloc(layout.size());
+            self.record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// runs `f` and returns the allocations it made, or `None` when the runner
+/// was built without the counting allocator
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    #[cfg(feature = "count-allocs")]
+    {
+        let (value, allocs) = GLOBAL.measure(f);
+        (value, Some(allocs))
+    }
+
+    #[cfg(not(feature = "count-allocs"))]
+    {
+        (f(), None)
+    }
+}
+
+/// formats a number of bytes using the largest sensible binary unit
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{}B", bytes),
+        1024..=1_048_575 => format!("{:.1}KiB", bytes as f64 / 1024.0),
+        1_048_576..=1_073_741_823 => format!("{:.1}MiB", bytes as f64 / 1_048_576.0),
+        _ => format!("{:.2}GiB", bytes as f64 / 1_073_741_824.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let counting = Counting::new();
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let large = Layout::from_size_align(1000, 8).unwrap();
+
+        // something that stays allocated shouldn't count towards the peak
+        let before = unsafe { counting.alloc(small) };
+
+        let (_, allocs) = counting.measure(|| unsafe {
+            let a = counting.alloc(large);
+            counting.dealloc(a, large);
+
+            let b = counting.alloc_zeroed(small);
+            let b = counting.realloc(b, small, 500);
+            counting.dealloc(b, Layout::from_size_align(500, 8).unwrap());
+        });
+
+        assert_eq!(
+            allocs,
+            Allocs {
+                allocations: 3,
+                bytes: 1600,
+                peak: 1000,
+            }
+        );
+
+        unsafe { counting.dealloc(before, small) };
+        assert_eq!(counting.live.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0MiB");
+        assert_eq!(format_bytes(3 << 30), "3.00GiB");
+    }
+}
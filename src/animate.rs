@@ -0,0 +1,70 @@
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::Frames;
+
+/// plays `frames` in the terminal, drawing each frame over the previous one
+/// after waiting for `delay`. frames are only colored when `NO_COLOR` isn't
+/// set. when stdout isn't a terminal the frames are written one after
+/// another instead, like [`dump`] does
+pub fn play(frames: Frames<'_>, delay: Duration) -> Result<(), String> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    if !out.is_terminal() {
+        return dump(frames, &mut out)
+            .and_then(|_| out.flush())
+            .map_err(|err| format!("couldn't write the animation: {}", err));
+    }
+
+    let color = std::env::var_os("NO_COLOR").is_none();
+
+    for (i, frame) in frames.enumerate() {
+        if i > 0 {
+            thread::sleep(delay);
+        }
+
+        // move to the top left and clear the screen in the same write as the
+        // frame, so it doesn't flicker
+        writeln!(out, "\x1b[H\x1b[J{}", frame.render(color))
+            .and_then(|_| out.flush())
+            .map_err(|err| format!("couldn't draw the animation: {}", err))?;
+    }
+
+    Ok(())
+}
+
+/// writes `frames` to `out` as plain text separated by blank lines, returns
+/// the number of frames written
+pub fn dump(frames: Frames<'_>, out: &mut impl Write) -> io::Result<usize> {
+    let mut count = 0;
+
+    for frame in frames {
+        writeln!(out, "{}\n", frame.render(false))?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::{Color, Frame};
+
+    #[test]
+    fn test_dump() {
+        let frames = (1..=2).map(|i| {
+            Frame::new(format!("step {}", i), "#.\n.#").with_palette(&[('#', Color::Red)])
+        });
+
+        let mut out = Vec::new();
+        assert_eq!(dump(Box::new(frames), &mut out).unwrap(), 2);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "step 1\n#.\n.#\n\nstep 2\n#.\n.#\n\n"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! The answers registry. Every day's docs record the accepted answers as
+//! "Your puzzle answer was N." lines, these are extracted into one
+//! `answers.txt` file per year so the solutions can be checked against them.
+//!
+//! Each line of an answers file holds the day, the part and the answer
+//! separated by whitespace. Blank lines and lines starting with `#` are
+//! ignored.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cli::AnswersArgs;
+use crate::registry::{repo_path, DAYS};
+
+const ANSWER_PREFIX: &str = "//! Your puzzle answer was ";
+
+/// the accepted answers for a year, keyed by (day, part)
+pub type Answers = BTreeMap<(u8, u8), String>;
+
+/// returns the path to the answers file for `year`
+pub fn answers_path(year: u16) -> PathBuf {
+    repo_path(format!("{}/answers.txt", year))
+}
+
+/// reads the answers file for `year`. a year without an answers file has no
+/// recorded answers
+pub fn read_answers(year: u16) -> Result<Answers, String> {
+    let path = answers_path(year);
+
+    if !path.exists() {
+        return Ok(Answers::new());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+
+    parse_answers(&contents).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+/// parses the contents of an answers file
+pub fn parse_answers(contents: &str) -> Result<Answers, String> {
+    let mut answers = Answers::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (day, part, answer) = match fields[..] {
+            [day, part, answer] => (day, part, answer),
+            _ => return Err(format!("line {}: expected 'day part answer'", i + 1)),
+        };
+
+        let day = day
+            .parse()
+            .map_err(|_| format!("line {}: invalid day '{}'", i + 1, day))?;
+        let part = part
+            .parse()
+            .map_err(|_| format!("line {}: invalid part '{}'", i + 1, part))?;
+
+        answers.insert((day, part), answer.to_string());
+    }
+
+    Ok(answers)
+}
+
+/// formats the answers the same way they're read back by `parse_answers`
+pub fn format_answers(year: u16, answers: &Answers) -> String {
+    let mut out = format!(
+        "# {} answers, extracted from the crate docs using `aoc answers`\n# day part answer\n",
+        year
+    );
+
+    for ((day, part), answer) in answers {
+        out.push_str(&format!("{:02} {} {}\n", day, part, answer));
+    }
+
+    out
+}
+
+/// returns the answers recorded in a day's crate docs. the first recorded
+/// answer belongs to part 1 and the second one to part 2
+pub fn answers_from_docs(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(ANSWER_PREFIX))
+        .map(|answer| answer.trim().trim_end_matches('.').to_string())
+        .take(2)
+        .collect()
+}
+
+/// extracts the answers from the docs of every day of a year into the year's
+/// answers file
+pub fn extract(args: &AnswersArgs) -> Result<(), String> {
+    let years: BTreeSet<u16> = DAYS
+        .iter()
+        .map(|day| day.year)
+        .filter(|&year| args.year.is_none_or(|y| y == year))
+        .collect();
+
+    if years.is_empty() {
+        return Err(format!(
+            "no solutions for {}",
+            args.year.unwrap_or_default()
+        ));
+    }
+
+    for year in years {
+        let mut answers = Answers::new();
+
+        for day in 1..=25 {
+            let dir = repo_path(format!("{}/day{:02}/src", year, day));
+            let source = ["lib.rs", "main.rs"]
+                .iter()
+                .find_map(|file| fs::read_to_string(dir.join(file)).ok());
+
+            if let Some(source) = source {
+                for (i, answer) in answers_from_docs(&source).into_iter().enumerate() {
+                    answers.insert((day, i as u8 + 1), answer);
+                }
+            }
+        }
+
+        let path = answers_path(year);
+        fs::write(&path, format_answers(year, &answers))
+            .map_err(|err| format!("failed to write {}: {}", path.display(), err))?;
+
+        println!("{}: {} answers", path.display(), answers.len());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_from_docs() {
+        let source = "\
+//! # Day 2: 1202 Program Alarm
+//!
+//! Your puzzle answer was 4945026.
+//!
+//! Your puzzle answer was 5296.
+//!
+";
+        assert_eq!(answers_from_docs(source), ["4945026", "5296"]);
+        assert!(answers_from_docs("fn main() {}").is_empty());
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let mut answers = Answers::new();
+        answers.insert((2, 1), "4945026".to_string());
+        answers.insert((2, 2), "5296".to_string());
+
+        let contents = format_answers(2019, &answers);
+        assert_eq!(parse_answers(&contents), Ok(answers));
+
+        assert!(parse_answers("02 1").is_err());
+        assert!(parse_answers("xx 1 5296").is_err());
+    }
+}
@@ -0,0 +1,377 @@
+//! Benchmarks each day by timing the four phases of solving a puzzle
+//! separately: reading the input file, parsing it and solving each part.
+//! Each day is run a few times untimed to warm up, then timed over a number
+//! of iterations and summarized by the median and 95th percentile.
+//!
+//! When the runner is built with the counting allocator (see [`crate::alloc`])
+//! the allocations of parsing and solving each part are reported as well.
+
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::alloc::{self, format_bytes, Allocs};
+use crate::cli::BenchArgs;
+use crate::registry::{repo_path, with_input, Day, DAYS};
+
+/// phases faster than this are too noisy to be flagged as a regression
+const MIN_REGRESSION: Duration = Duration::from_micros(100);
+
+/// the timings of a single phase over every iteration
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Timing {
+    /// summarizes the duration of every iteration
+    fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+
+        Timing {
+            median_ns: samples[samples.len() / 2].as_nanos() as u64,
+            p95_ns: samples[percentile_index(samples.len(), 95)].as_nanos() as u64,
+        }
+    }
+}
+
+/// the benchmark results for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub read: Timing,
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing,
+    /// only measured when the counting allocator is installed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<DayAllocs>,
+}
+
+/// the allocations of the phases of a single day that depend on the solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAllocs {
+    pub parse: Allocs,
+    pub part1: Allocs,
+    pub part2: Allocs,
+}
+
+impl DayBench {
+    fn phases(&self) -> [(&'static str, Timing); 4] {
+        [
+            ("read", self.read),
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+}
+
+/// returns the index of the `p`th percentile in `n` sorted samples using the
+/// nearest-rank method
+fn percentile_index(n: usize, p: usize) -> usize {
+    ((n * p).div_ceil(100)).clamp(1, n) - 1
+}
+
+/// times every phase of `day` over `iterations` runs after `warmup` untimed
+/// runs. the allocations are the same on every run, so only the last run's
+/// are kept
+pub fn bench_day(day: &Day, warmup: u32, iterations: u32) -> Result<DayBench, String> {
+    let mut samples: [Vec<Duration>; 4] = Default::default();
+    let mut allocs = None;
+
+    for i in 0..warmup + iterations {
+        let start = Instant::now();
+        let (path, contents) = day.read_input(None)?;
+        let read = start.elapsed();
+
+        let start = Instant::now();
+        let (input, parse_allocs) = alloc::measure(|| day.parse(black_box(&contents)));
+        let input = input.map_err(|err| err.with_file(path).to_string())?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let (answer, part1_allocs) = alloc::measure(|| day.solve(1, input.as_ref()));
+        black_box(answer);
+        let part1 = start.elapsed();
+
+        let start = Instant::now();
+        let (answer, part2_allocs) = alloc::measure(|| day.solve(2, input.as_ref()));
+        black_box(answer);
+        let part2 = start.elapsed();
+
+        if let (Some(parse), Some(part1), Some(part2)) = (parse_allocs, part1_allocs, part2_allocs)
+        {
+            allocs = Some(DayAllocs {
+                parse,
+                part1,
+                part2,
+            });
+        }
+
+        if i >= warmup {
+            for (phase, elapsed) in [read, parse, part1, part2].into_iter().enumerate() {
+                samples[phase].push(elapsed);
+            }
+        }
+    }
+
+    Ok(DayBench {
+        year: day.year,
+        day: day.day,
+        read: Timing::from_samples(&mut samples[0]),
+        parse: Timing::from_samples(&mut samples[1]),
+        part1: Timing::from_samples(&mut samples[2]),
+        part2: Timing::from_samples(&mut samples[3]),
+        allocs,
+    })
+}
+
+/// formats a duration in nanoseconds using the largest sensible unit
+pub fn format_ns(ns: u64) -> String {
+    match ns {
+        0..=999 => format!("{}ns", ns),
+        1_000..=999_999 => format!("{:.1}µs", ns as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+/// returns the phases of `current` that are more than `threshold` percent
+/// slower than the same day in `baseline`
+fn regressions(current: &DayBench, baseline: &[DayBench], threshold: f64) -> Vec<&'static str> {
+    let base = match baseline
+        .iter()
+        .find(|b| b.year == current.year && b.day == current.day)
+    {
+        Some(base) => base,
+        None => return vec![],
+    };
+
+    current
+        .phases()
+        .iter()
+        .zip(base.phases().iter())
+        .filter(|((_, now), (_, before))| {
+            let limit = before.median_ns as f64 * (1.0 + threshold / 100.0);
+            now.median_ns as f64 > limit
+                && now.median_ns.saturating_sub(before.median_ns) > MIN_REGRESSION.as_nanos() as u64
+        })
+        .map(|((phase, _), _)| *phase)
+        .collect()
+}
+
+fn print_table(results: &[DayBench], baseline: &[DayBench], threshold: f64) {
+    println!(
+        "{:<12} {:>21} {:>21} {:>21} {:>21}",
+        "", "read", "parse", "part1", "part2"
+    );
+    println!(
+        "{:<12} {:>21} {:>21} {:>21} {:>21}",
+        "", "median / p95", "median / p95", "median / p95", "median / p95"
+    );
+
+    for result in results {
+        let mut line = format!("{:<12}", format!("{} day {:02}", result.year, result.day));
+
+        for (_, timing) in result.phases() {
+            let cell = format!(
+                "{} / {}",
+                format_ns(timing.median_ns),
+                format_ns(timing.p95_ns)
+            );
+            line.push_str(&format!(" {:>21}", cell));
+        }
+
+        let slower = regressions(result, baseline, threshold);
+        if !slower.is_empty() {
+            line.push_str(&format!("  REGRESSION: {}", slower.join(", ")));
+        }
+
+        println!("{}", line);
+    }
+
+    if results.iter().all(|result| result.allocs.is_none()) {
+        return;
+    }
+
+    println!(
+        "\n{:<12} {:>27} {:>27} {:>27}",
+        "", "parse", "part1", "part2"
+    );
+    println!(
+        "{:<12} {:>27} {:>27} {:>27}",
+        "", "allocs / bytes / peak", "allocs / bytes / peak", "allocs / bytes / peak"
+    );
+
+    for result in results {
+        let allocs = match result.allocs {
+            Some(allocs) => allocs,
+            None => continue,
+        };
+
+        let mut line = format!("{:<12}", format!("{} day {:02}", result.year, result.day));
+
+        for phase in [allocs.parse, allocs.part1, allocs.part2] {
+            let cell = format!(
+                "{} / {} / {}",
+                phase.allocations,
+                format_bytes(phase.bytes),
+                format_bytes(phase.peak)
+            );
+            line.push_str(&format!(" {:>27}", cell));
+        }
+
+        println!("{}", line);
+    }
+}
+
+fn read_baseline(path: &Path) -> Result<Vec<DayBench>, String> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read baseline {}: {}", path.display(), err))?;
+
+    serde_json::from_str(&contents)
+        .map_err(|err| format!("invalid baseline {}: {}", path.display(), err))
+}
+
+/// benchmarks every selected day and reports the results
+pub fn bench(args: &BenchArgs) -> Result<(), String> {
+    let days: Vec<&Day> = DAYS
+        .iter()
+        .filter(|day| args.year.is_none_or(|y| y == day.year))
+        .filter(|day| args.day.is_none_or(|d| d == day.day))
+        .collect();
+
+    if days.is_empty() {
+        return Err("no solutions found".to_string());
+    }
+
+    let results = with_input(days)
+        .iter()
+        .map(|day| bench_day(day, args.warmup, args.iterations))
+        .collect::<Result<Vec<DayBench>, String>>()?;
+
+    let baseline_path = args
+        .baseline
+        .clone()
+        .unwrap_or_else(|| repo_path("bench/baseline.json"));
+
+    if args.save_baseline {
+        // only replace the days that were benchmarked
+        let mut baseline = read_baseline(&baseline_path)?;
+        baseline.retain(|b| !results.iter().any(|r| r.year == b.year && r.day == b.day));
+        baseline.extend(results.iter().cloned());
+        baseline.sort_by_key(|b| (b.year, b.day));
+
+        if let Some(dir) = baseline_path.parent() {
+            fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+
+        let json = serde_json::to_string_pretty(&baseline).map_err(|err| err.to_string())?;
+        fs::write(&baseline_path, json + "\n")
+            .map_err(|err| format!("failed to write {}: {}", baseline_path.display(), err))?;
+    }
+
+    let baseline = if args.save_baseline {
+        vec![]
+    } else {
+        read_baseline(&baseline_path)?
+    };
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&results).map_err(|err| err.to_string())?;
+        println!("{}", json);
+    } else {
+        print_table(&results, &baseline, args.threshold);
+    }
+
+    let regressed = results
+        .iter()
+        .filter(|result| !regressions(result, &baseline, args.threshold).is_empty())
+        .count();
+
+    if regressed > 0 {
+        return Err(format!(
+            "{} days regressed by more than {}% compared to {}",
+            regressed,
+            args.threshold,
+            baseline_path.display()
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(median_ns: u64) -> Timing {
+        Timing {
+            median_ns,
+            p95_ns: median_ns,
+        }
+    }
+
+    fn day_bench(part1_ns: u64) -> DayBench {
+        DayBench {
+            year: 2020,
+            day: 15,
+            read: timing(1_000),
+            parse: timing(1_000),
+            part1: timing(part1_ns),
+            part2: timing(1_000),
+            allocs: None,
+        }
+    }
+
+    #[test]
+    fn test_timing() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+        assert_eq!(
+            Timing::from_samples(&mut samples),
+            Timing {
+                median_ns: 11,
+                p95_ns: 19
+            }
+        );
+
+        let mut samples = vec![Duration::from_nanos(7)];
+        assert_eq!(Timing::from_samples(&mut samples), timing(7));
+    }
+
+    #[test]
+    fn test_format_ns() {
+        assert_eq!(format_ns(512), "512ns");
+        assert_eq!(format_ns(1_500), "1.5µs");
+        assert_eq!(format_ns(2_250_000), "2.2ms");
+        assert_eq!(format_ns(1_575_000_000), "1.57s");
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = vec![day_bench(10_000_000)];
+
+        assert!(regressions(&day_bench(11_000_000), &baseline, 25.0).is_empty());
+        assert_eq!(
+            regressions(&day_bench(20_000_000), &baseline, 25.0),
+            ["part1"]
+        );
+
+        // tiny phases are too noisy to compare
+        let baseline = vec![day_bench(1_000)];
+        assert!(regressions(&day_bench(5_000), &baseline, 25.0).is_empty());
+
+        // days missing from the baseline can't regress
+        assert!(regressions(&day_bench(20_000_000), &[], 25.0).is_empty());
+    }
+}
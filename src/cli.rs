@@ -0,0 +1,192 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{ArgAction, ArgGroup, Args, Parser, Subcommand, ValueEnum};
+
+/// Runs the Advent of Code solutions in this repository
+#[derive(Debug, Parser)]
+#[command(name = "aoc", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Prints the debug output of the solutions to stderr, -vv prints even
+    /// more. Without it, AOC_LOG (e.g. AOC_LOG=aoc_2020_day16=debug) decides
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Runs a single day, every day of a year or every day of every year
+    Run(RunArgs),
+    /// Extracts the answers recorded in each day's docs into YEAR/answers.txt
+    Answers(AnswersArgs),
+    /// Checks every solution against the answers in YEAR/answers.txt
+    Verify(VerifyArgs),
+    /// Times reading, parsing and solving each day
+    Bench(BenchArgs),
+    /// Checks every solution against the examples from its puzzle description
+    Examples(ExamplesArgs),
+    /// Updates the progress section of the README with the status and
+    /// runtime of every part
+    Report(ReportArgs),
+    /// Rebuilds and re-runs a day every time its source, input or example
+    /// files change
+    Watch(WatchArgs),
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["year", "all"])))]
+pub struct RunArgs {
+    /// The year to run (e.g. 2020)
+    pub year: Option<u16>,
+
+    /// The day to run (e.g. 10), runs every day of the year when omitted
+    pub day: Option<u8>,
+
+    /// Runs every day of every year
+    #[arg(long)]
+    pub all: bool,
+
+    /// Only runs a single part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Reads the puzzle input from PATH instead of the day's input.txt, or
+    /// from stdin when PATH is -
+    #[arg(long, value_name = "PATH", requires = "day")]
+    pub input: Option<String>,
+
+    /// The number of parts to run at the same time
+    #[arg(long, short, default_value_t = 1, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub jobs: usize,
+
+    /// Gives up on a part after SECS seconds and reports it as timed out
+    #[arg(long, value_name = "SECS", value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+
+    /// How to print the answers. Anything that isn't an answer is printed
+    /// to stderr
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "animate")]
+    pub format: Format,
+
+    /// Plays every generation of the day's simulation in the terminal
+    /// before printing the answer, for the days that are step simulations
+    #[arg(long, requires = "day")]
+    pub animate: bool,
+
+    /// The delay between the frames of the animation, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 100, requires = "animate")]
+    pub delay: u64,
+
+    /// Writes the frames of the animation to PATH as plain text instead of
+    /// playing them
+    #[arg(long, value_name = "PATH", requires = "animate")]
+    pub dump: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A line per part, e.g. `2020 day 01 part 1: 997899`
+    Text,
+    /// A JSON object per part and line, with the year, day, part, answer and
+    /// the time it took to solve the part in nanoseconds
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct AnswersArgs {
+    /// Only extracts the answers for a single year
+    pub year: Option<u16>,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Only verifies a single year
+    pub year: Option<u16>,
+
+    /// Only verifies a single day of the year
+    #[arg(requires = "year")]
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, Args)]
+pub struct ExamplesArgs {
+    /// Only checks a single year
+    pub year: Option<u16>,
+
+    /// Only checks a single day of the year
+    #[arg(requires = "year")]
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    /// Only reports a single year
+    pub year: Option<u16>,
+
+    /// The number of timed iterations per day
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+
+    /// Prints the section instead of updating the README
+    #[arg(long)]
+    pub print: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// The year of the day to watch (e.g. 2020)
+    pub year: u16,
+
+    /// The day to watch (e.g. 10)
+    pub day: u8,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// Only benchmarks a single year
+    pub year: Option<u16>,
+
+    /// Only benchmarks a single day of the year
+    #[arg(requires = "year")]
+    pub day: Option<u8>,
+
+    /// The number of timed iterations per day
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+
+    /// The number of untimed iterations to run before timing a day
+    #[arg(long, default_value_t = 1)]
+    pub warmup: u32,
+
+    /// Prints the results as JSON instead of a table
+    #[arg(long)]
+    pub json: bool,
+
+    /// The baseline to compare the results against [default:
+    /// bench/baseline.json in the repository]
+    #[arg(long, value_name = "PATH")]
+    pub baseline: Option<PathBuf>,
+
+    /// Overwrites the baseline with the results instead of comparing them
+    #[arg(long)]
+    pub save_baseline: bool,
+
+    /// How much slower (in percent) a phase has to be than the baseline to be
+    /// flagged as a regression
+    #[arg(long, default_value_t = 50.0)]
+    pub threshold: f64,
+}
+
+/// parses a positive number of seconds, e.g. `10` or `0.5`
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(format!(
+            "expected a positive number of seconds, found '{}'",
+            s
+        )),
+    }
+}
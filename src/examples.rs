@@ -0,0 +1,111 @@
+use aoc_common::{Answer, Example};
+
+use crate::cli::ExamplesArgs;
+use crate::registry::{Day, DAYS};
+
+/// runs a single example of `day` and returns the answer
+fn run_example(day: &Day, example: &Example) -> Result<Answer, String> {
+    let contents = example.load(&day.crate_dir())?;
+    let input = day.parse(&contents).map_err(|err| err.to_string())?;
+
+    Ok(day.solve(example.part, input.as_ref()))
+}
+
+/// runs every example of `day`, returns a description of each failing one
+fn check_day(day: &Day, mut report: impl FnMut(&str, Option<&str>)) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    for (i, example) in day.examples.iter().enumerate() {
+        let name = format!(
+            "{} day {:02} part {} example {}",
+            day.year,
+            day.day,
+            example.part,
+            i + 1
+        );
+
+        let failure = match run_example(day, example) {
+            Ok(answer) if answer.matches(example.answer) => None,
+            Ok(answer) => Some(format!("expected {}, got {}", example.answer, answer)),
+            Err(err) => Some(err),
+        };
+
+        report(&name, failure.as_deref());
+
+        if let Some(failure) = failure {
+            failures.push(format!("{}: {}", name, failure));
+        }
+    }
+
+    failures
+}
+
+/// returns the parts of `day` that don't have an example
+fn uncovered_parts(day: &Day) -> Vec<u8> {
+    (1..=2)
+        .filter(|&part| day.examples.iter().all(|example| example.part != part))
+        .collect()
+}
+
+/// runs the examples of each selected day and lists the parts without any
+pub fn examples(args: &ExamplesArgs) -> Result<(), String> {
+    let days: Vec<&Day> = DAYS
+        .iter()
+        .filter(|day| args.year.is_none_or(|y| y == day.year))
+        .filter(|day| args.day.is_none_or(|d| d == day.day))
+        .collect();
+
+    if days.is_empty() {
+        return Err("no solutions found".to_string());
+    }
+
+    let (mut passed, mut failed) = (0, 0);
+    let mut uncovered = Vec::new();
+
+    for day in days {
+        check_day(day, |name, failure| match failure {
+            None => {
+                passed += 1;
+                println!("{}: pass", name);
+            }
+            Some(failure) => {
+                failed += 1;
+                println!("{}: FAIL ({})", name, failure);
+            }
+        });
+
+        for part in uncovered_parts(day) {
+            uncovered.push(format!("{} day {:02} part {}", day.year, day.day, part));
+        }
+    }
+
+    println!("\n{} passed, {} failed", passed, failed);
+
+    if !uncovered.is_empty() {
+        println!("\n{} parts have no examples:", uncovered.len());
+        for part in &uncovered {
+            println!("  {}", part);
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} examples failed", failed, passed + failed));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let failures: Vec<String> = DAYS
+            .iter()
+            .flat_map(|day| check_day(day, |_, _| {}))
+            .collect();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
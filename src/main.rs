@@ -11,9 +11,86 @@
 //! **Use the links below to jump to a specific year**
 //!
 //! - [**AOC**](https://hkennyv.github.io/aoc/aoc/index.html)
-//! - [**2019**](https://hkennyv.github.io/aoc/aoc/2019/doc/aoc_2019/index.html)
-//! - [**2020**](https://hkennyv.github.io/aoc/aoc/2020/doc/aoc_2020/index.html)
-//! - [**2021**](https://hkennyv.github.io/aoc/aoc/2021/doc/aoc_2021/index.html)
+//! - [**2019**](https://hkennyv.github.io/aoc/aoc_2019/index.html)
+//! - [**2020**](https://hkennyv.github.io/aoc/aoc_2020/index.html)
+//! - [**2021**](https://hkennyv.github.io/aoc/aoc_2021/index.html)
+//! - [**2022**](https://hkennyv.github.io/aoc/aoc_2022/index.html)
+//!
+//! <!-- report:start -->
+//! # Progress
+//!
+//! ⭐ is a part whose answer matches the one in the year's `answers.txt`.
+//!
+//! | Year | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | Stars |
+//! |---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|---|
+//! | 2019 | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  | 8 |
+//! | 2020 | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ |  |  | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ |  |  |  |  |  |  | 34 |
+//! | 2021 | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ | ⭐⭐ |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  | 20 |
+//! | 2022 |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  |  | 0 |
+//!
+//! ✅ verified, ❌ doesn't match the recorded answer, ❔ no recorded answer, 🚧 not implemented yet, 📭 no input to run it on. The times are the median time to solve each part (without reading and parsing the input).
+//!
+//! ## 2019
+//!
+//! | Day | Part 1 | Part 2 |
+//! |---|---|---|
+//! | 01 | ✅ 197ns | ✅ 1.7µs |
+//! | 02 | ✅ 629ns | ✅ 1.3ms |
+//! | 03 | ✅ 48.3ms | ✅ 44.4ms |
+//! | 04 | ✅ 3.6ms | ✅ 90.0ms |
+//!
+//! ## 2020
+//!
+//! | Day | Part 1 | Part 2 |
+//! |---|---|---|
+//! | 01 | ✅ 6.4µs | ✅ 3.5µs |
+//! | 02 | ✅ 27.6µs | ✅ 58.4µs |
+//! | 03 | ✅ 3.3µs | ✅ 15.3µs |
+//! | 04 | ✅ 92.3µs | ✅ 170.7ms |
+//! | 05 | ✅ 436.3µs | ✅ 470.6µs |
+//! | 06 | ✅ 659.0µs | ✅ 731.2µs |
+//! | 07 | ✅ 511.7µs | ✅ 459.4µs |
+//! | 08 | ✅ 22.0µs | ✅ 1.6ms |
+//! | 09 | ✅ 17.5µs | ✅ 21.5µs |
+//! | 10 | ✅ 1.9µs | ✅ 1.8µs |
+//! | 11 | ✅ 32.4ms | ✅ 115.1ms |
+//! | 12 | ✅ 9.4µs | ✅ 9.2µs |
+//! | 13 | ❔ 2.2µs | ❔ 3.0µs |
+//! | 14 | ❔ 135.6µs | ❔ 119.2ms |
+//! | 15 | ✅ 13.5µs | ✅ 1.76s |
+//! | 16 | ✅ 10.2µs | ✅ 364.4µs |
+//! | 17 | ✅ 1.0ms | ✅ 6.6ms |
+//! | 18 | ✅ 1.1ms | ✅ 1.3ms |
+//! | 19 | ✅ 4.9ms | ✅ 89.2ms |
+//! | 20 | 🚧 | 🚧 |
+//! | 21 | 🚧 | 🚧 |
+//! | 22 | 🚧 | 🚧 |
+//! | 23 | 🚧 | 🚧 |
+//! | 24 | 🚧 | 🚧 |
+//! | 25 | 📭 | 📭 |
+//!
+//! ## 2021
+//!
+//! | Day | Part 1 | Part 2 |
+//! |---|---|---|
+//! | 01 | ✅ 2.0µs | ✅ 2.3µs |
+//! | 02 | ✅ 5.0µs | ✅ 4.3µs |
+//! | 03 | ✅ 5.4µs | ✅ 45.5µs |
+//! | 04 | ✅ 329.1µs | ✅ 1.3ms |
+//! | 05 | ✅ 11.1ms | ✅ 23.2ms |
+//! | 06 | ✅ 679ns | ✅ 864ns |
+//! | 07 | ✅ 80.4µs | ✅ 2.7ms |
+//! | 08 | ✅ 3.9µs | ✅ 1.9ms |
+//! | 09 | ✅ 150.8µs | ✅ 1.6ms |
+//! | 10 | ✅ 200.3µs | ✅ 203.2µs |
+//! | 11 | ❔ 653.2µs | 🚧 |
+//!
+//! ## 2022
+//!
+//! | Day | Part 1 | Part 2 |
+//! |---|---|---|
+//! | 03 | ❔ 481.1µs | ❔ 441.9µs |
+//! <!-- report:end -->
 //!
 //! # Introduction
 //!
@@ -39,8 +116,9 @@
 //!
 //! The repository is structured as follows:
 //!
-//! - **Each year is a [cargo workspace](https://doc.rust-lang.org/book/ch14-03-cargo-workspaces.html)**
+//! - **Every year and day is a member of the root [cargo workspace](https://doc.rust-lang.org/book/ch14-03-cargo-workspaces.html)**
 //! - **Each day is a [cargo crate](https://doc.rust-lang.org/book/ch07-01-packages-and-crates.html)**
+//! - **Each day implements the `Solution` trait from the shared [`common`](common) crate**
 //!
 //! Cheers & happy coding!
 //!
@@ -69,17 +147,69 @@
 //! ### Prerequisites
 //!
 //! - install rust using [rustup](https://www.rust-lang.org/tools/install) (this
-//! should install the cargo toolchain as well). using the nightly build is
-//! recommended
+//!   should install the cargo toolchain as well). using the nightly build is
+//!   recommended
 //!
 //! ## Running the code
 //!
-//! Any day of the AOC can be run by going into that day's directory and running:
+//! Any day of the AOC can be run from the root of the repository using the
+//! `aoc` runner:
 //!
 //! ```
 //! # e.g. if you want to run 2020/day10
-//! $ cd 2020/day10
-//! $ cargo run --release
+//! $ cargo run --release -- run 2020 10
+//!
+//! # only run part 2, using a different input file
+//! $ cargo run --release -- run 2020 10 --part 2 --input path/to/input.txt
+//!
+//! # run every day of 2020
+//! $ cargo run --release -- run 2020
+//!
+//! # run every day of every year
+//! $ cargo run --release -- run --all
+//!
+//! # run 8 parts at a time, and give up on the ones that take longer than 10s
+//! $ cargo run --release -- run --all --jobs 8 --timeout 10
+//! ```
+//!
+//! A part that panics or times out is reported as crashed or timed out, and
+//! everything else still runs, including the other part of the same day. Since
+//! a thread can't be stopped, a part that timed out keeps running in the
+//! background until the runner exits.
+//!
+//! For scripts and dashboards, `--format json` prints every answer as a JSON
+//! object on its own line, and everything else (crashes, timeouts, debug output)
+//! goes to stderr:
+//!
+//! ```
+//! $ cargo run --release -- run 2020 1 --format json
+//! {"year":2020,"day":1,"part":1,"answer":538464,"elapsed_ns":17312}
+//! {"year":2020,"day":1,"part":2,"answer":278783190,"elapsed_ns":11612}
+//! ```
+//!
+//! Answers are numbers when they fit in 64 bits and strings otherwise, a list of
+//! rows for the answers drawn with pixels, and `null` for parts that aren't
+//! solved yet.
+//!
+//! The solutions log their debug output with the [`log`](https://docs.rs/log)
+//! crate (re-exported by `aoc-common`), which is hidden unless you ask for it.
+//! `-v` shows the debug output on stderr and `-vv` shows everything, or use
+//! `AOC_LOG` to pick what to show:
+//!
+//! ```
+//! $ cargo run --release -- run 2020 16 -v
+//! $ AOC_LOG=aoc_2020_day13=debug cargo run --release -- run 2020 13
+//! ```
+//!
+//! Each day can also still be run on its own by going into that day's
+//! directory and running `cargo run --release` (or `cargo run --release -p
+//! aoc-2020-day10` from anywhere in the repository).
+//!
+//! The runner links every year by default. Each year has a feature (`y2019`,
+//! `y2020`, `y2021` and `y2022`) so you can only compile the years you're working on:
+//!
+//! ```
+//! $ cargo run --release --no-default-features --features y2021 -- run 2021 6
 //! ```
 //!
 //! **NOTE:** everyone's input for the advent of code is different. I've committed
@@ -88,6 +218,51 @@
 //! input. Each crate reads from an "input.txt" file in their respective
 //! directories.
 //!
+//! The input is looked up in the following order, no matter which directory the
+//! code is run from:
+//!
+//! 1. the path passed with `--input PATH` (to the `aoc` runner or a day's binary)
+//! 2. stdin, when the path is `-`
+//! 3. `$AOC_INPUT_DIR/<year>/<day>/input.txt`, if `AOC_INPUT_DIR` is set and the
+//!    file exists (handy for keeping your own inputs outside the repository)
+//! 4. the "input.txt" committed in the day's crate directory
+//!
+//! If an input can't be parsed, the runner stops with the path, line and column of
+//! the offending text instead of panicking:
+//!
+//! ```
+//! $ cargo run --release -- run 2021 2 --input bad.txt
+//! error: bad.txt:2:6: expected a usize ("x")
+//! ```
+//!
+//! ### Animations
+//!
+//! The days that are step simulations (2020/day11, 2020/day17 and 2021/day11)
+//! can play every generation in the terminal before printing the answer, which
+//! helps a lot when a rule is subtly wrong:
+//!
+//! ```
+//! # play the seating system, 50ms per frame
+//! $ cargo run --release -- run 2020 11 --animate --delay 50
+//!
+//! # write the frames to a file instead, without any colors
+//! $ cargo run --release -- run 2020 17 --part 1 --animate --dump frames.txt
+//! ```
+//!
+//! ### Watching a day
+//!
+//! While working on a puzzle, `aoc watch` re-runs a day every time you save one
+//! of its files (anything in `src/`, its `input.txt` or the files of its
+//! examples). It rebuilds the runner, checks the examples, runs the real input
+//! and shows how the answers changed since the last run:
+//!
+//! ```
+//! $ cargo run --release -- watch 2020 10
+//! ...
+//! 2020 day 10 part 1: 2368 (unchanged)
+//! 2020 day 10 part 2: 1727094849536 (was 1727094849535)
+//! ```
+//!
 //! # Documentation
 //!
 //! **Bonus:** I've added the description of each day's challenge to the crate
@@ -124,13 +299,144 @@
 //! cargo test
 //! ```
 //!
-//! Alternatively, run tests for the entire workspace using:
+//! Alternatively, run tests for every year and day using:
 //!
 //! ```
-//! cargo test --all
+//! cargo test --workspace
 //! ```
 //!
+//! Days that keep a slower reference implementation next to the optimized one
+//! (e.g. `2021/day06`) also have [proptest](https://docs.rs/proptest) tests
+//! that check both agree on random inputs. A failure is shrunk to a minimal
+//! counterexample and saved in the crate's `proptest-regressions` directory,
+//! which should be committed so the case is always re-run.
+//!
+//! ## Verifying the answers
+//!
+//! The answers that were accepted for my input are recorded in each day's docs
+//! and extracted into an `answers.txt` file per year (e.g. `2020/answers.txt`)
+//! using:
+//!
+//! ```
+//! cargo run --release -- answers
+//! ```
+//!
+//! Every solution can then be checked against the recorded answers, which fails
+//! if a refactor changes an answer:
+//!
+//! ```
+//! # verify everything, a single year or a single day
+//! $ cargo run --release -- verify
+//! $ cargo run --release -- verify 2020
+//! $ cargo run --release -- verify 2020 10
+//! ```
+//!
+//! ## Checking the examples
+//!
+//! The worked examples from the puzzle descriptions are declared next to each
+//! solution (see `aoc_common::example`), and can be checked without any real
+//! input. Parts without an example are listed at the end:
+//!
+//! ```
+//! # check everything, a single year or a single day
+//! $ cargo run --release -- examples
+//! $ cargo run --release -- examples 2021
+//! $ cargo run --release -- examples 2021 6
+//! ```
+//!
+//! ## Benchmarks
+//!
+//! Every day can be benchmarked with `aoc bench`. Reading the input, parsing it
+//! and solving each part are timed separately over a few iterations (after a
+//! warm-up) and reported as the median and 95th percentile:
+//!
+//! ```
+//! # benchmark everything, a single year or a single day
+//! $ cargo run --release -- bench
+//! $ cargo run --release -- bench 2020
+//! $ cargo run --release -- bench 2020 15 --iterations 20 --json
+//! ```
+//!
+//! The results are compared against the committed `bench/baseline.json` and any
+//! phase that got slower than the `--threshold` (50% by default) is flagged as a
+//! regression. After an optimization, update the baseline using:
+//!
+//! ```
+//! cargo run --release -- bench --save-baseline
+//! ```
+//!
+//! To see how much memory each day uses, build the runner with the counting
+//! allocator. It's opt-in since it slows down every allocation a little, and adds
+//! the number of allocations, the bytes allocated and the peak live bytes of
+//! parsing and solving each part to the bench output:
+//!
+//! ```
+//! cargo run --release --features count-allocs -- bench 2020 15
+//! ```
+//!
+//! ## Updating the progress table
+//!
+//! The progress table at the top of this README is generated from a real run of
+//! every day: each part is checked against the recorded answers and timed. To
+//! update it after solving a day (in release mode, or the times are way off):
+//!
+//! ```
+//! cargo run --release -- report
+//! ```
+//!
+
+mod alloc;
+mod animate;
+mod answers;
+mod bench;
+mod cli;
+mod examples;
+mod pool;
+mod registry;
+mod report;
+mod runner;
+mod verify;
+mod watch;
+
+use std::process;
+
+use aoc_common::log::LevelFilter;
+use clap::Parser;
+
+use cli::{Cli, Command};
+
+/// sends the log output of the solutions to stderr. `-v` shows the debug
+/// output and `-vv` everything, otherwise `AOC_LOG` decides (only warnings by
+/// default)
+fn init_logging(verbose: u8) {
+    let mut builder = env_logger::Builder::new();
+    builder.format_timestamp(None);
+
+    match verbose {
+        0 => builder.filter_level(LevelFilter::Warn).parse_env("AOC_LOG"),
+        1 => builder.filter_level(LevelFilter::Debug),
+        _ => builder.filter_level(LevelFilter::Trace),
+    };
+
+    builder.init();
+}
 
 fn main() {
-    println!("Hello, world!");
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    let res = match &cli.command {
+        Command::Run(args) => runner::run(args),
+        Command::Answers(args) => answers::extract(args),
+        Command::Verify(args) => verify::verify(args),
+        Command::Bench(args) => bench::bench(args),
+        Command::Examples(args) => examples::examples(args),
+        Command::Report(args) => report::report(args),
+        Command::Watch(args) => watch::watch(args),
+    };
+
+    if let Err(err) = res {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
 }
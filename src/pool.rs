@@ -0,0 +1,236 @@
+//! A small thread pool for running days concurrently. Every task runs on its
+//! own thread and at most `jobs` of them run at the same time.
+//!
+//! A thread can't be killed, so a task that runs past the timeout is reported
+//! as timed out and abandoned: its slot is given to the next task and its
+//! result is ignored if it ever finishes. Panics are caught and reported with
+//! their message instead of being printed.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// how a task ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// the task returned, after running for the duration
+    Finished(T, Duration),
+    /// the task panicked, with the panic message and its location
+    Panicked(String),
+    /// the task was still running after the timeout
+    TimedOut(Duration),
+}
+
+/// the panic hook is global, so only one run can replace it at a time
+static HOOK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// the message of the last panic on this thread, set by the hook in `run`
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// whether this thread runs a task, the hook in `run` leaves the panics
+    /// of every other thread to the previous hook
+    static IN_TASK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// runs `tasks` using up to `jobs` threads at a time and calls `report` with
+/// the index and outcome of each task as soon as it ends, in the order they
+/// end. tasks that run longer than `timeout` are reported as timed out
+pub fn run<T, F>(
+    tasks: Vec<F>,
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut report: impl FnMut(usize, Outcome<T>),
+) where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let _guard = HOOK.lock().unwrap_or_else(|err| err.into_inner());
+    let previous: Arc<dyn Fn(&PanicHookInfo<'_>) + Sync + Send> = Arc::from(panic::take_hook());
+    let hook = Arc::clone(&previous);
+    panic::set_hook(Box::new(move |info| match IN_TASK.with(Cell::get) {
+        true => {
+            let message = info.to_string().replace('\n', " ");
+            PANIC.with(|panic| *panic.borrow_mut() = Some(message));
+        }
+        false => hook(info),
+    }));
+
+    // the previous hook has to be back even if `report` panics. a drop guard
+    // can't do it, the hook can't be set while the thread is panicking
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        schedule(tasks, jobs, timeout, &mut report)
+    }));
+    panic::set_hook(Box::new(move |info| previous(info)));
+
+    if let Err(payload) = result {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// the body of [`run`], with the panic hook already in place
+fn schedule<T, F>(
+    tasks: Vec<F>,
+    jobs: usize,
+    timeout: Option<Duration>,
+    report: &mut impl FnMut(usize, Outcome<T>),
+) where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let mut pending = tasks.into_iter().enumerate();
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+
+    loop {
+        while running.len() < jobs.max(1) {
+            let Some((i, task)) = pending.next() else {
+                break;
+            };
+
+            let tx = tx.clone();
+            thread::spawn(move || {
+                IN_TASK.with(|in_task| in_task.set(true));
+                let start = Instant::now();
+                let outcome = match panic::catch_unwind(AssertUnwindSafe(task)) {
+                    Ok(value) => Outcome::Finished(value, start.elapsed()),
+                    Err(_) => Outcome::Panicked(
+                        PANIC
+                            .with(|panic| panic.borrow_mut().take())
+                            .unwrap_or_else(|| "panicked".to_string()),
+                    ),
+                };
+
+                // the receiver is gone if the task timed out and the run ended
+                let _ = tx.send((i, outcome));
+            });
+
+            running.insert(i, Instant::now());
+        }
+
+        if running.is_empty() {
+            break;
+        }
+
+        // wait for the next task to end, but not past the earliest deadline
+        let received = match timeout {
+            Some(timeout) => {
+                let wait = running
+                    .values()
+                    .map(|start| (*start + timeout).saturating_duration_since(Instant::now()))
+                    .min()
+                    .unwrap_or_default();
+                rx.recv_timeout(wait).ok()
+            }
+            None => rx.recv().ok(),
+        };
+
+        // results of tasks that already timed out are ignored
+        if let Some((i, outcome)) = received {
+            if running.remove(&i).is_some() {
+                report(i, outcome);
+            }
+        }
+
+        if let Some(timeout) = timeout {
+            let mut expired: Vec<usize> = running
+                .iter()
+                .filter(|(_, start)| start.elapsed() >= timeout)
+                .map(|(i, _)| *i)
+                .collect();
+            expired.sort_unstable();
+
+            for i in expired {
+                running.remove(&i);
+                report(i, Outcome::TimedOut(timeout));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// the tests swap the panic hook, so they can't run at the same time
+    static TESTS: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_run() {
+        let _lock = TESTS.lock().unwrap_or_else(|err| err.into_inner());
+
+        let tasks: Vec<Box<dyn FnOnce() -> u32 + Send>> = vec![
+            Box::new(|| 1),
+            Box::new(|| panic!("boom")),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(5));
+                3
+            }),
+            Box::new(|| 4),
+        ];
+
+        let mut outcomes: Vec<Option<Outcome<u32>>> = vec![None; 4];
+        run(tasks, 2, Some(Duration::from_millis(200)), |i, outcome| {
+            assert!(outcomes[i].is_none(), "task {} was reported twice", i);
+            outcomes[i] = Some(outcome);
+        });
+
+        assert!(matches!(outcomes[0], Some(Outcome::Finished(1, _))));
+        match &outcomes[1] {
+            Some(Outcome::Panicked(message)) => {
+                assert!(message.contains("boom"), "{}", message);
+                assert!(message.contains("src/pool.rs"), "{}", message);
+            }
+            outcome => panic!("expected a panic, got {:?}", outcome),
+        }
+        assert_eq!(
+            outcomes[2],
+            Some(Outcome::TimedOut(Duration::from_millis(200)))
+        );
+        assert!(matches!(outcomes[3], Some(Outcome::Finished(4, _))));
+    }
+
+    #[test]
+    fn test_run_without_timeout() {
+        let _lock = TESTS.lock().unwrap_or_else(|err| err.into_inner());
+
+        let tasks: Vec<_> = (0..10).map(|i| move || i * 2).collect();
+
+        let mut sum = 0;
+        run(tasks, 3, None, |_, outcome| match outcome {
+            Outcome::Finished(value, _) => sum += value,
+            outcome => panic!("unexpected {:?}", outcome),
+        });
+
+        assert_eq!(sum, 90);
+    }
+
+    #[test]
+    fn test_report_panics() {
+        static PANICS: AtomicUsize = AtomicUsize::new(0);
+        let _lock = TESTS.lock().unwrap_or_else(|err| err.into_inner());
+
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(|_| {
+            PANICS.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        // the report runs on this thread, so its panic goes to the previous
+        // hook instead of being swallowed like a task's
+        let result = panic::catch_unwind(|| {
+            run(vec![|| 1], 1, None, |_, _: Outcome<u32>| panic!("report"));
+        });
+        assert!(result.is_err());
+        assert_eq!(PANICS.load(Ordering::SeqCst), 1);
+
+        // and the previous hook is back afterwards
+        let _ = panic::catch_unwind(|| panic!("after"));
+        assert_eq!(PANICS.load(Ordering::SeqCst), 2);
+
+        panic::set_hook(default);
+    }
+}
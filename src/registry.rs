@@ -0,0 +1,259 @@
+//! The registry of every day in the repository. Each day implements
+//! [`Solution`], which the registry wraps so the runner can treat every day
+//! the same way.
+
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{input, Answer, Example, Frames, ParseError, Solution};
+
+/// returns `path` relative to the root of the repository, so nothing depends
+/// on the current working directory
+pub fn repo_path(path: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/// a single day of the advent calendar. the day's [`Solution`] is stored as
+/// plain function pointers with the parsed input type erased, so that every
+/// day can live in the same table
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// the worked examples from the puzzle description
+    pub examples: &'static [Example],
+    parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    part1: fn(&dyn Any) -> Answer,
+    part2: fn(&dyn Any) -> Answer,
+    animate: fn(&dyn Any, u8) -> Option<Frames<'_>>,
+}
+
+impl Day {
+    // unused when the runner is built without any years
+    #[cfg_attr(
+        not(any(
+            feature = "y2019",
+            feature = "y2020",
+            feature = "y2021",
+            feature = "y2022"
+        )),
+        allow(dead_code)
+    )]
+    const fn new<S: Solution>(year: u16, day: u8) -> Self
+    where
+        S::Input: 'static,
+    {
+        Day {
+            year,
+            day,
+            examples: S::EXAMPLES,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+            animate: animate::<S>,
+        }
+    }
+
+    /// returns the day's crate directory
+    pub fn crate_dir(&self) -> PathBuf {
+        repo_path(format!("{}/day{:02}", self.year, self.day))
+    }
+
+    /// returns true if the day has an input to run on when no explicit path
+    /// is given (a day can be solved without the input being committed)
+    pub fn has_input(&self) -> bool {
+        input::find(&self.crate_dir(), "input.txt").is_some()
+    }
+
+    /// reads the day's input, from `explicit` if given, and returns the path
+    /// it was read from along with its contents. see [`aoc_common::input`]
+    /// for where else the input is looked up
+    pub fn read_input(&self, explicit: Option<&str>) -> Result<(String, String), String> {
+        input::load_with_path(explicit, &self.crate_dir(), "input.txt")
+            .map_err(|err| err.to_string())
+    }
+
+    /// reads the day's input and parses it. a parse error is reported with
+    /// the path of the input and the position of the offending text
+    pub fn load(&self, explicit: Option<&str>) -> Result<Box<dyn Any>, String> {
+        let (path, contents) = self.read_input(explicit)?;
+        self.parse_file(&path, &contents)
+    }
+
+    /// parses `contents`, read from `path`. a parse error is reported with
+    /// the path and the position of the offending text
+    pub fn parse_file(&self, path: &str, contents: &str) -> Result<Box<dyn Any>, String> {
+        self.parse(contents)
+            .map_err(|err| err.with_file(path).to_string())
+    }
+
+    /// parses the contents of the day's input
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// solves `part` (1 or 2) using the input returned by `parse`
+    pub fn solve(&self, part: u8, input: &dyn Any) -> Answer {
+        match part {
+            1 => (self.part1)(input),
+            2 => (self.part2)(input),
+            _ => Answer::Unsolved,
+        }
+    }
+
+    /// returns every generation of the simulation behind `part`, if the day
+    /// can be animated. see [`aoc_common::animation`]
+    pub fn animate<'a>(&self, part: u8, input: &'a dyn Any) -> Option<Frames<'a>> {
+        (self.animate)(input, part)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Box<dyn Any>, ParseError>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1<S: Solution>(input: &dyn Any) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part1(
+        input
+            .downcast_ref()
+            .expect("input was parsed by another day"),
+    )
+}
+
+fn part2<S: Solution>(input: &dyn Any) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part2(
+        input
+            .downcast_ref()
+            .expect("input was parsed by another day"),
+    )
+}
+
+fn animate<S: Solution>(input: &dyn Any, part: u8) -> Option<Frames<'_>>
+where
+    S::Input: 'static,
+{
+    S::animate(
+        input
+            .downcast_ref()
+            .expect("input was parsed by another day"),
+        part,
+    )
+}
+
+/// returns whether `year` was left out of this build because its `yXXXX`
+/// feature is disabled
+pub fn is_disabled(year: u16) -> bool {
+    match year {
+        2019 => !cfg!(feature = "y2019"),
+        2020 => !cfg!(feature = "y2020"),
+        2021 => !cfg!(feature = "y2021"),
+        2022 => !cfg!(feature = "y2022"),
+        _ => false,
+    }
+}
+
+/// drops the days without an input from a selection of several days, with a
+/// note on stderr for each. a single day is kept, so running it still says
+/// where its input was looked for
+pub fn with_input(days: Vec<&'static Day>) -> Vec<&'static Day> {
+    if days.len() < 2 {
+        return days;
+    }
+
+    days.into_iter()
+        .filter(|day| {
+            let has_input = day.has_input();
+            if !has_input {
+                eprintln!("{} day {:02}: no input, skipped", day.year, day.day);
+            }
+            has_input
+        })
+        .collect()
+}
+
+/// every day in the repository, ordered by year and day. only the years
+/// whose `yXXXX` feature is enabled are included
+pub const DAYS: &[Day] = &[
+    #[cfg(feature = "y2019")]
+    Day::new::<aoc_2019_day01::Day01>(2019, 1),
+    #[cfg(feature = "y2019")]
+    Day::new::<aoc_2019_day02::Day02>(2019, 2),
+    #[cfg(feature = "y2019")]
+    Day::new::<aoc_2019_day03::Day03>(2019, 3),
+    #[cfg(feature = "y2019")]
+    Day::new::<aoc_2019_day04::Day04>(2019, 4),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day01::Day01>(2020, 1),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day02::Day02>(2020, 2),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day03::Day03>(2020, 3),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day04::Day04>(2020, 4),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day05::Day05>(2020, 5),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day06::Day06>(2020, 6),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day07::Day07>(2020, 7),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day08::Day08>(2020, 8),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day09::Day09>(2020, 9),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day10::Day10>(2020, 10),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day11::Day11>(2020, 11),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day12::Day12>(2020, 12),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day13::Day13>(2020, 13),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day14::Day14>(2020, 14),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day15::Day15>(2020, 15),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day16::Day16>(2020, 16),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day17::Day17>(2020, 17),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day18::Day18>(2020, 18),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day19::Day19>(2020, 19),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day20::Day20>(2020, 20),
+    #[cfg(feature = "y2020")]
+    Day::new::<aoc_2020_day25::Day25>(2020, 25),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day01::Day01>(2021, 1),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day02::Day02>(2021, 2),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day03::Day03>(2021, 3),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day04::Day04>(2021, 4),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day05::Day05>(2021, 5),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day06::Day06>(2021, 6),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day07::Day07>(2021, 7),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day08::Day08>(2021, 8),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day09::Day09>(2021, 9),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day10::Day10>(2021, 10),
+    #[cfg(feature = "y2021")]
+    Day::new::<aoc_2021_day11::Day11>(2021, 11),
+    #[cfg(feature = "y2022")]
+    Day::new::<aoc_2022_day03::Day03>(2022, 3),
+];
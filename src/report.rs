@@ -0,0 +1,397 @@
+//! Generates the progress section of the README: a star matrix of every
+//! year and day, followed by a table per year with the status and median
+//! runtime of each part. The section is kept between the [`START`] and
+//! [`END`] markers in both `README.md` and the `src/main.rs` docs (which the
+//! README is generated from), so it survives regenerating the README.
+
+use std::collections::BTreeSet;
+use std::fs;
+
+use aoc_common::Answer;
+
+use crate::answers::read_answers;
+use crate::bench::{bench_day, format_ns};
+use crate::cli::ReportArgs;
+use crate::registry::{repo_path, DAYS};
+
+pub const START: &str = "<!-- report:start -->";
+pub const END: &str = "<!-- report:end -->";
+
+/// the state of a single part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// the answer matches the recorded one
+    Verified,
+    /// the answer doesn't match the recorded one
+    Wrong,
+    /// solved, but there's no recorded answer to compare against
+    Unverified,
+    /// not solved yet
+    Stubbed,
+    /// registered, but there's no input to run it on
+    NoInput,
+}
+
+impl Status {
+    fn from_answer(answer: &Answer, expected: Option<&String>) -> Status {
+        match expected {
+            _ if !answer.is_solved() => Status::Stubbed,
+            Some(expected) if answer.matches(expected) => Status::Verified,
+            Some(_) => Status::Wrong,
+            None => Status::Unverified,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Status::Verified => "✅",
+            Status::Wrong => "❌",
+            Status::Unverified => "❔",
+            Status::Stubbed => "🚧",
+            Status::NoInput => "📭",
+        }
+    }
+}
+
+/// the status of a single part, and how long it takes to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartReport {
+    pub status: Status,
+    pub median_ns: Option<u64>,
+}
+
+impl PartReport {
+    const STUBBED: PartReport = PartReport {
+        status: Status::Stubbed,
+        median_ns: None,
+    };
+
+    const NO_INPUT: PartReport = PartReport {
+        status: Status::NoInput,
+        median_ns: None,
+    };
+
+    fn cell(&self) -> String {
+        match self.median_ns {
+            Some(ns) if self.status != Status::Stubbed => {
+                format!("{} {}", self.status.symbol(), format_ns(ns))
+            }
+            _ => self.status.symbol().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parts: [PartReport; 2],
+}
+
+/// returns the days that have a crate in `year`'s directory, whether or not
+/// they're registered
+fn day_crates(year: u16) -> BTreeSet<u8> {
+    let entries = match fs::read_dir(repo_path(year.to_string())) {
+        Ok(entries) => entries,
+        Err(_) => return BTreeSet::new(),
+    };
+
+    entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            name.to_str()?.strip_prefix("day")?.parse().ok()
+        })
+        .collect()
+}
+
+/// solves and times every registered day of `years`. days that only have a
+/// crate (e.g. a stub that was never registered) are reported as stubbed, and
+/// registered days without an input as having none
+fn collect(years: &BTreeSet<u16>, iterations: u32) -> Result<Vec<DayReport>, String> {
+    let mut reports = Vec::new();
+
+    for &year in years {
+        let expected = read_answers(year)?;
+        let mut days = day_crates(year);
+        days.extend(DAYS.iter().filter(|d| d.year == year).map(|d| d.day));
+
+        for day in days {
+            let registered = match DAYS.iter().find(|d| d.year == year && d.day == day) {
+                Some(registered) => registered,
+                None => {
+                    reports.push(DayReport {
+                        year,
+                        day,
+                        parts: [PartReport::STUBBED; 2],
+                    });
+                    continue;
+                }
+            };
+
+            if !registered.has_input() {
+                reports.push(DayReport {
+                    year,
+                    day,
+                    parts: [PartReport::NO_INPUT; 2],
+                });
+                continue;
+            }
+
+            let input = registered.load(None)?;
+            let timings = bench_day(registered, 1, iterations)?;
+
+            let part = |part: u8, median_ns: u64| PartReport {
+                status: Status::from_answer(
+                    &registered.solve(part, input.as_ref()),
+                    expected.get(&(day, part)),
+                ),
+                median_ns: Some(median_ns),
+            };
+
+            reports.push(DayReport {
+                year,
+                day,
+                parts: [
+                    part(1, timings.part1.median_ns),
+                    part(2, timings.part2.median_ns),
+                ],
+            });
+        }
+    }
+
+    Ok(reports)
+}
+
+/// renders the progress section, using the README's heading levels
+pub fn render(reports: &[DayReport]) -> String {
+    let years: BTreeSet<u16> = reports.iter().map(|r| r.year).collect();
+    let stars = |report: &DayReport| {
+        report
+            .parts
+            .iter()
+            .filter(|part| part.status == Status::Verified)
+            .count()
+    };
+
+    let mut out = String::from("## Progress\n\n");
+    out.push_str("⭐ is a part whose answer matches the one in the year's `answers.txt`.\n\n");
+
+    // the star matrix
+    out.push_str("| Year |");
+    for day in 1..=25 {
+        out.push_str(&format!(" {} |", day));
+    }
+    out.push_str(" Stars |\n|---|");
+    out.push_str(&"---|".repeat(26));
+    out.push('\n');
+
+    for &year in &years {
+        let days: Vec<&DayReport> = reports.iter().filter(|r| r.year == year).collect();
+
+        out.push_str(&format!("| {} |", year));
+        for day in 1..=25 {
+            let cell = match days.iter().find(|r| r.day == day) {
+                Some(report) => "⭐".repeat(stars(report)),
+                None => String::new(),
+            };
+            out.push_str(&format!(" {} |", cell));
+        }
+        out.push_str(&format!(
+            " {} |\n",
+            days.iter().map(|r| stars(r)).sum::<usize>()
+        ));
+    }
+
+    out.push_str(
+        "\n✅ verified, ❌ doesn't match the recorded answer, ❔ no recorded answer, \
+         🚧 not implemented yet, 📭 no input to run it on. The times are the median \
+         time to solve each part (without reading and parsing the input).\n",
+    );
+
+    for &year in &years {
+        out.push_str(&format!(
+            "\n### {}\n\n| Day | Part 1 | Part 2 |\n|---|---|---|\n",
+            year
+        ));
+
+        for report in reports.iter().filter(|r| r.year == year) {
+            out.push_str(&format!(
+                "| {:02} | {} | {} |\n",
+                report.day,
+                report.parts[0].cell(),
+                report.parts[1].cell()
+            ));
+        }
+    }
+
+    out
+}
+
+/// returns `section` as `//!` docs, with every heading one level up since
+/// the README's headings are one level below the docs' headings
+fn to_docs(section: &str) -> String {
+    section
+        .lines()
+        .map(|line| {
+            let line = match line.strip_prefix('#') {
+                Some(heading) if line.starts_with("##") => heading,
+                _ => line,
+            };
+
+            if line.is_empty() {
+                "//!".to_string()
+            } else {
+                format!("//! {}", line)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+        + "\n"
+}
+
+/// replaces everything between the markers in `contents` with `section`.
+/// `prefix` is put in front of the lines of the markers (e.g. `//! `)
+pub fn replace_section(contents: &str, section: &str, prefix: &str) -> Result<String, String> {
+    let start_marker = format!("{}{}\n", prefix, START);
+    let end_marker = format!("{}{}", prefix, END);
+
+    let start = contents
+        .find(&start_marker)
+        .ok_or_else(|| format!("missing the {} marker", START))?
+        + start_marker.len();
+    let end = contents[start..]
+        .find(&end_marker)
+        .ok_or_else(|| format!("missing the {} marker", END))?
+        + start;
+
+    Ok(format!(
+        "{}{}{}",
+        &contents[..start],
+        section,
+        &contents[end..]
+    ))
+}
+
+/// generates the progress section and writes it to the README and the
+/// `src/main.rs` docs, or prints it
+pub fn report(args: &ReportArgs) -> Result<(), String> {
+    let years: BTreeSet<u16> = DAYS
+        .iter()
+        .map(|day| day.year)
+        .filter(|&year| args.year.is_none_or(|y| y == year))
+        .collect();
+
+    if years.is_empty() {
+        return Err("no solutions found".to_string());
+    }
+
+    let section = render(&collect(&years, args.iterations)?);
+
+    if args.print {
+        print!("{}", section);
+        return Ok(());
+    }
+
+    for (file, section, prefix) in [
+        ("README.md", section.clone(), ""),
+        ("src/main.rs", to_docs(&section), "//! "),
+    ] {
+        let path = repo_path(file);
+        let contents = fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+
+        let updated = replace_section(&contents, &section, prefix)
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+
+        fs::write(&path, updated)
+            .map_err(|err| format!("failed to write {}: {}", path.display(), err))?;
+        println!("updated {}", path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(status: Status, median_ns: u64) -> PartReport {
+        PartReport {
+            status,
+            median_ns: Some(median_ns),
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        let expected = "42".to_string();
+
+        assert_eq!(
+            Status::from_answer(&Answer::Signed(42), Some(&expected)),
+            Status::Verified
+        );
+        assert_eq!(
+            Status::from_answer(&Answer::Signed(41), Some(&expected)),
+            Status::Wrong
+        );
+        assert_eq!(
+            Status::from_answer(&Answer::Signed(41), None),
+            Status::Unverified
+        );
+        assert_eq!(
+            Status::from_answer(&Answer::Unsolved, Some(&expected)),
+            Status::Stubbed
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let reports = [
+            DayReport {
+                year: 2020,
+                day: 1,
+                parts: [
+                    part(Status::Verified, 1_500),
+                    part(Status::Verified, 2_250_000),
+                ],
+            },
+            DayReport {
+                year: 2020,
+                day: 3,
+                parts: [part(Status::Unverified, 512), PartReport::STUBBED],
+            },
+        ];
+
+        let section = render(&reports);
+
+        assert!(section.contains("| 2020 | ⭐⭐ |  |  |"));
+        assert!(section.contains(" 2 |\n"));
+        assert!(section.contains("| 01 | ✅ 1.5µs | ✅ 2.2ms |\n"));
+        assert!(section.contains("| 03 | ❔ 512ns | 🚧 |\n"));
+    }
+
+    #[test]
+    fn test_to_docs() {
+        assert_eq!(
+            to_docs("## Progress\n\n| a |\n### 2020\n"),
+            "//! # Progress\n//!\n//! | a |\n//! ## 2020\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let contents = format!("# aoc\n{}\nold\n{}\nrest\n", START, END);
+        assert_eq!(
+            replace_section(&contents, "new\n", ""),
+            Ok(format!("# aoc\n{}\nnew\n{}\nrest\n", START, END))
+        );
+
+        let docs = format!("//! {}\n//! old\n//! {}\n", START, END);
+        assert_eq!(
+            replace_section(&docs, "//! new\n", "//! "),
+            Ok(format!("//! {}\n//! new\n//! {}\n", START, END))
+        );
+
+        assert!(replace_section("no markers", "new", "").is_err());
+    }
+}
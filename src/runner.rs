@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use aoc_common::Answer;
+use serde::Serialize;
+
+use crate::animate;
+use crate::bench::format_ns;
+use crate::cli::{Format, RunArgs};
+use crate::pool::{self, Outcome};
+use crate::registry::{is_disabled, with_input, Day, DAYS};
+
+/// a single answer, as printed by `--format json`
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_ns: u64,
+}
+
+/// prints the answer to `part` of `day` to stdout in `format`
+fn print_answer(day: &Day, part: u8, answer: &Answer, elapsed: Duration, format: Format) {
+    match format {
+        // pictures start on their own line
+        Format::Text => match answer {
+            Answer::Pixels(_) => {
+                println!("{} day {:02} part {}:\n{}", day.year, day.day, part, answer)
+            }
+            _ => println!("{} day {:02} part {}: {}", day.year, day.day, part, answer),
+        },
+        Format::Json => {
+            let record = Record {
+                year: day.year,
+                day: day.day,
+                part,
+                answer,
+                elapsed_ns: elapsed.as_nanos() as u64,
+            };
+
+            println!(
+                "{}",
+                serde_json::to_string(&record).expect("a record is always valid JSON")
+            )
+        }
+    }
+}
+
+/// runs every day selected by `args` and prints the answers for each part.
+/// the days run on up to `--jobs` threads, but are always printed in order
+pub fn run(args: &RunArgs) -> Result<(), String> {
+    let days: Vec<&'static Day> = DAYS
+        .iter()
+        .filter(|day| {
+            args.all || (Some(day.year) == args.year && args.day.is_none_or(|d| d == day.day))
+        })
+        .collect();
+
+    if days.is_empty() {
+        return Err(match (args.year, args.day) {
+            (Some(year), _) if is_disabled(year) => format!(
+                "{} isn't included in this build, enable it with --features y{}",
+                year, year
+            ),
+            (Some(year), Some(day)) => format!("no solution for {} day {}", year, day),
+            (Some(year), None) => format!("no solutions for {}", year),
+            _ => "no solutions found".to_string(),
+        });
+    }
+
+    if args.animate {
+        return animate_days(&days, args);
+    }
+
+    let days = match args.input {
+        Some(_) => days,
+        None => with_input(days),
+    };
+
+    let parts: Vec<u8> = (1..=2)
+        .filter(|&p| args.part.is_none_or(|part| part == p))
+        .collect();
+
+    // each input is read once (stdin can't be read twice), then every part
+    // parses it and runs as its own task, so a part that finishes is printed
+    // even if the other one crashes or times out
+    let inputs: Vec<Result<(String, Arc<str>), String>> = days
+        .iter()
+        .map(|day| {
+            let (path, contents) = day.read_input(args.input.as_deref())?;
+            Ok((path, Arc::from(contents)))
+        })
+        .collect();
+
+    let parts: Vec<(usize, u8)> = (0..days.len())
+        .flat_map(|i| parts.iter().map(move |&part| (i, part)))
+        .collect();
+
+    let tasks: Vec<_> = parts
+        .iter()
+        .map(|&(i, part)| {
+            let day = days[i];
+            let input = inputs[i].clone();
+            move || solve(day, part, input)
+        })
+        .collect();
+
+    // the outcomes that can't be printed until the parts before them are
+    let mut finished = BTreeMap::new();
+    let mut next = 0;
+    // what went wrong with each day, a problem reading or parsing the input
+    // is the same for both parts and is only reported once
+    let mut problems: Vec<(usize, String)> = Vec::new();
+
+    pool::run(tasks, args.jobs, args.timeout, |task, outcome| {
+        finished.insert(task, outcome);
+
+        while let Some(outcome) = finished.remove(&next) {
+            let (i, part) = parts[next];
+            next += 1;
+
+            let Some(problem) = report(days[i], part, outcome, args.format) else {
+                continue;
+            };
+            if problems.last() == Some(&(i, problem.clone())) {
+                continue;
+            }
+
+            if days.len() > 1 {
+                eprintln!("{} day {:02}: {}", days[i].year, days[i].day, problem);
+            }
+            problems.push((i, problem));
+        }
+    });
+
+    let mut failed: Vec<usize> = problems.iter().map(|(i, _)| *i).collect();
+    failed.dedup();
+
+    match failed.len() {
+        0 => Ok(()),
+        // a single day fails with its own errors, like any other command
+        _ if days.len() == 1 => Err(problems
+            .into_iter()
+            .map(|(_, problem)| problem)
+            .collect::<Vec<_>>()
+            .join("\n")),
+        n => Err(format!("{} of {} days didn't finish", n, days.len())),
+    }
+}
+
+/// parses the input of `day`, read from the path it's paired with, and
+/// solves `part`. returns the answer and how long solving it took
+fn solve(
+    day: &Day,
+    part: u8,
+    input: Result<(String, Arc<str>), String>,
+) -> Result<(Answer, Duration), String> {
+    let (path, contents) = input?;
+    let input = day.parse_file(&path, &contents)?;
+
+    let start = Instant::now();
+    let answer = day.solve(part, input.as_ref());
+    Ok((answer, start.elapsed()))
+}
+
+/// prints the answer to a part that finished. returns what went wrong
+/// otherwise
+fn report(
+    day: &Day,
+    part: u8,
+    outcome: Outcome<Result<(Answer, Duration), String>>,
+    format: Format,
+) -> Option<String> {
+    match outcome {
+        Outcome::Finished(Ok((answer, elapsed)), _) => {
+            print_answer(day, part, &answer, elapsed, format);
+            None
+        }
+        Outcome::Finished(Err(err), _) => Some(err),
+        Outcome::Panicked(message) => Some(format!("part {} crashed: {}", part, message)),
+        Outcome::TimedOut(timeout) => Some(format!(
+            "part {} timed out after {}",
+            part,
+            format_ns(timeout.as_nanos() as u64)
+        )),
+    }
+}
+
+/// plays the animation of each selected part of `days` before printing its
+/// answer, or dumps the frames to `--dump`
+fn animate_days(days: &[&Day], args: &RunArgs) -> Result<(), String> {
+    // every animated part is dumped to the same file
+    let mut dump = match &args.dump {
+        Some(path) => {
+            Some(BufWriter::new(File::create(path).map_err(|err| {
+                format!("couldn't create {}: {}", path.display(), err)
+            })?))
+        }
+        None => None,
+    };
+
+    for day in days {
+        let input = day.load(args.input.as_deref())?;
+        let parts: Vec<u8> = (1..=2)
+            .filter(|&p| args.part.is_none_or(|part| part == p))
+            .collect();
+
+        // parts without an animation are skipped, unless none of them have one
+        if parts
+            .iter()
+            .all(|&p| day.animate(p, input.as_ref()).is_none())
+        {
+            return Err(match args.part {
+                Some(part) => format!(
+                    "{} day {:02} part {} can't be animated",
+                    day.year, day.day, part
+                ),
+                None => format!("{} day {:02} can't be animated", day.year, day.day),
+            });
+        }
+
+        for part in parts {
+            if let Some(frames) = day.animate(part, input.as_ref()) {
+                match (&args.dump, dump.as_mut()) {
+                    (Some(path), Some(out)) => {
+                        let count = animate::dump(frames, out)
+                            .and_then(|count| out.flush().map(|_| count))
+                            .map_err(|err| {
+                                format!("couldn't write to {}: {}", path.display(), err)
+                            })?;
+
+                        eprintln!(
+                            "wrote {} frames of part {} to {}",
+                            count,
+                            part,
+                            path.display()
+                        );
+                    }
+                    _ => animate::play(frames, Duration::from_millis(args.delay))?,
+                }
+            }
+
+            let start = Instant::now();
+            let answer = day.solve(part, input.as_ref());
+            print_answer(day, part, &answer, start.elapsed(), args.format);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let record = Record {
+            year: 2020,
+            day: 1,
+            part: 2,
+            answer: &Answer::Signed(7),
+            elapsed_ns: 3000,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"year":2020,"day":1,"part":2,"answer":7,"elapsed_ns":3000}"#
+        );
+    }
+}
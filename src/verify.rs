@@ -0,0 +1,69 @@
+use std::collections::btree_map::{BTreeMap, Entry};
+
+use crate::answers::read_answers;
+use crate::cli::VerifyArgs;
+use crate::registry::{with_input, Day, DAYS};
+
+/// runs each selected day against its committed input and compares the
+/// answers to the ones in the year's answers file
+pub fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let days: Vec<&Day> = DAYS
+        .iter()
+        .filter(|day| args.year.is_none_or(|y| y == day.year))
+        .filter(|day| args.day.is_none_or(|d| d == day.day))
+        .collect();
+
+    if days.is_empty() {
+        return Err("no solutions found".to_string());
+    }
+
+    let days = with_input(days);
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut answers = BTreeMap::new();
+
+    for day in days {
+        // each year's answers file is only read once
+        let expected_answers = match answers.entry(day.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(read_answers(day.year)?),
+        };
+
+        let input = day.load(None)?;
+
+        for part in 1..=2 {
+            let answer = day.solve(part, input.as_ref());
+            let name = format!("{} day {:02} part {}", day.year, day.day, part);
+
+            match expected_answers.get(&(day.day, part)) {
+                Some(expected) if answer.matches(expected) => {
+                    passed += 1;
+                    println!("{}: pass", name);
+                }
+                Some(expected) => {
+                    failed += 1;
+                    println!("{}: FAIL (expected {}, got {})", name, expected, answer);
+                }
+                None => {
+                    missing += 1;
+                    println!("{}: missing (got {})", name, answer);
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} answers didn't match",
+            failed,
+            passed + failed + missing
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+//! Watches a day's crate and re-runs it whenever its source, input or example
+//! files change: the runner is rebuilt (with the same profile and features as
+//! the running one), then the day's examples are checked and it's run on the
+//! real input, and the answers are compared to the ones from the previous run.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use aoc_common::example::ExampleInput;
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde_json::Value;
+
+use crate::cli::WatchArgs;
+use crate::registry::{is_disabled, repo_path, Day, DAYS};
+
+/// editors often write a file in a few steps, every change within this long
+/// after the first one is handled by the same run
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// returns true if a change to `path` should re-run the day: anything in the
+/// crate's `src/`, its input or one of its example `files`
+fn is_watched(crate_dir: &Path, files: &[&str], path: &Path) -> bool {
+    let relative = match path.strip_prefix(crate_dir) {
+        Ok(relative) => relative,
+        Err(_) => return false,
+    };
+
+    relative.starts_with("src")
+        || relative == Path::new("input.txt")
+        || files.iter().any(|file| relative == Path::new(file))
+}
+
+/// the features the running binary was built with, so rebuilding it doesn't
+/// change which years are included
+fn features() -> Vec<&'static str> {
+    [
+        ("y2019", cfg!(feature = "y2019")),
+        ("y2020", cfg!(feature = "y2020")),
+        ("y2021", cfg!(feature = "y2021")),
+        ("y2022", cfg!(feature = "y2022")),
+        ("count-allocs", cfg!(feature = "count-allocs")),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(feature, _)| feature)
+    .collect()
+}
+
+/// rebuilds the runner, returns false if it doesn't compile. cargo's own
+/// output (the compiler errors) is shown as is
+fn build() -> Result<bool, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+
+    let mut command = Command::new(cargo);
+    command
+        .args([
+            "build",
+            "--quiet",
+            "--no-default-features",
+            "--manifest-path",
+        ])
+        .arg(repo_path("Cargo.toml"))
+        .arg("--features")
+        .arg(features().join(","));
+
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    let status = command
+        .status()
+        .map_err(|err| format!("couldn't run cargo: {}", err))?;
+
+    Ok(status.success())
+}
+
+/// runs `day` on its real input using the runner at `exe`, returns the
+/// answer to each part
+fn solve(exe: &Path, day: &Day) -> Result<BTreeMap<u8, String>, String> {
+    let output = Command::new(exe)
+        .args(["run", &day.year.to_string(), &day.day.to_string()])
+        .args(["--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| format!("couldn't run {}: {}", exe.display(), err))?;
+
+    if !output.status.success() {
+        return Err(format!("{} day {:02} failed", day.year, day.day));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| {
+            let record: Value = serde_json::from_str(line)
+                .map_err(|err| format!("invalid answer '{}': {}", line, err))?;
+
+            let part = record["part"].as_u64().unwrap_or_default() as u8;
+            let answer = match &record["answer"] {
+                Value::String(s) => s.clone(),
+                Value::Null => "not implemented".to_string(),
+                // pixels are a list of rows
+                Value::Array(rows) => rows
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<&str>>()
+                    .join("\n"),
+                answer => answer.to_string(),
+            };
+
+            Ok((part, answer))
+        })
+        .collect()
+}
+
+/// describes each answer in `current` compared to the one in `previous`
+fn diff(previous: &BTreeMap<u8, String>, current: &BTreeMap<u8, String>) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.get(part) {
+            None => format!("part {}: {}", part, answer),
+            Some(before) if before == answer => format!("part {}: {} (unchanged)", part, answer),
+            Some(before) => format!("part {}: {} (was {})", part, answer, before),
+        })
+        .collect()
+}
+
+/// rebuilds and re-runs `day`, returns its answers if it ran
+fn rerun(exe: &Path, day: &Day) -> Result<Option<BTreeMap<u8, String>>, String> {
+    println!("building...");
+    if !build()? {
+        println!("the build failed");
+        return Ok(None);
+    }
+
+    println!("\nchecking the examples...");
+    Command::new(exe)
+        .args(["examples", &day.year.to_string(), &day.day.to_string()])
+        .status()
+        .map_err(|err| format!("couldn't run {}: {}", exe.display(), err))?;
+
+    println!("\nrunning {} day {:02}...", day.year, day.day);
+    match solve(exe, day) {
+        Ok(answers) => Ok(Some(answers)),
+        Err(err) => {
+            println!("{}", err);
+            Ok(None)
+        }
+    }
+}
+
+/// re-runs a day every time one of its files changes, until it's interrupted
+pub fn watch(args: &WatchArgs) -> Result<(), String> {
+    let day = DAYS
+        .iter()
+        .find(|day| day.year == args.year && day.day == args.day)
+        .ok_or_else(|| match is_disabled(args.year) {
+            true => format!(
+                "{} isn't included in this build, enable it with --features y{}",
+                args.year, args.year
+            ),
+            false => format!("no solution for {} day {}", args.year, args.day),
+        })?;
+
+    // once the runner is rebuilt, the path of the running binary isn't valid
+    // anymore on some platforms, so it's looked up before the first build
+    let exe: PathBuf =
+        env::current_exe().map_err(|err| format!("couldn't find the runner: {}", err))?;
+
+    let crate_dir = day.crate_dir();
+    let files: Vec<&str> = day
+        .examples
+        .iter()
+        .filter_map(|example| match example.input {
+            ExampleInput::File(name) => Some(name),
+            _ => None,
+        })
+        .collect();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).map_err(|err| format!("couldn't watch: {}", err))?;
+    watcher
+        .watch(&crate_dir, RecursiveMode::Recursive)
+        .map_err(|err| format!("couldn't watch {}: {}", crate_dir.display(), err))?;
+
+    let mut previous = BTreeMap::new();
+
+    loop {
+        if let Some(answers) = rerun(&exe, day)? {
+            for line in diff(&previous, &answers) {
+                println!("{} day {:02} {}", day.year, day.day, line);
+            }
+            previous = answers;
+        }
+
+        println!("\nwatching {} for changes...", crate_dir.display());
+
+        // wait for a change to one of the day's files, then for the rest of
+        // the changes made at the same time
+        loop {
+            let event = rx
+                .recv()
+                .map_err(|_| "stopped watching for changes".to_string())?
+                .map_err(|err| format!("couldn't watch: {}", err))?;
+
+            if !matches!(event.kind, EventKind::Access(_))
+                && event
+                    .paths
+                    .iter()
+                    .any(|path| is_watched(&crate_dir, &files, path))
+            {
+                break;
+            }
+        }
+
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_watched() {
+        let crate_dir = Path::new("/aoc/2020/day16");
+        let files = ["sample.txt"];
+
+        assert!(is_watched(crate_dir, &files, &crate_dir.join("src/lib.rs")));
+        assert!(is_watched(crate_dir, &files, &crate_dir.join("src/a/b.rs")));
+        assert!(is_watched(crate_dir, &files, &crate_dir.join("input.txt")));
+        assert!(is_watched(crate_dir, &files, &crate_dir.join("sample.txt")));
+
+        assert!(!is_watched(crate_dir, &files, &crate_dir.join("notes.txt")));
+        assert!(!is_watched(
+            crate_dir,
+            &files,
+            Path::new("/aoc/2020/day17/src/lib.rs")
+        ));
+    }
+
+    #[test]
+    fn test_diff() {
+        let answers = |answers: &[(u8, &str)]| -> BTreeMap<u8, String> {
+            answers
+                .iter()
+                .map(|(part, answer)| (*part, answer.to_string()))
+                .collect()
+        };
+
+        let previous = answers(&[(1, "42"), (2, "7")]);
+        let current = answers(&[(1, "42"), (2, "8")]);
+
+        assert_eq!(
+            diff(&previous, &current),
+            ["part 1: 42 (unchanged)", "part 2: 8 (was 7)"]
+        );
+        assert_eq!(
+            diff(&BTreeMap::new(), &current),
+            ["part 1: 42", "part 2: 8"]
+        );
+    }
+}